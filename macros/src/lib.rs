@@ -0,0 +1,143 @@
+//! A procedural macro alternative to generating the code for
+//! [fluent-typed](https://docs.rs/fluent-typed) in a build script.
+use fluent_typed::{BuildOptions, Builder, FtlOutputOptions, ValidationPolicy};
+use proc_macro::TokenStream;
+use std::path::Path;
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, LitBool, LitStr, Token, parse_macro_input};
+
+/// Generates the `L10n` enum and the `L10nLanguage` functions for the locales
+/// in the given folder, which is relative to the root of the package.
+///
+/// ```ignore
+/// mod l10n {
+///     fluent_typed_macros::l10n!("locales");
+/// }
+/// ```
+///
+/// The default language is "en", and can be changed with:
+///
+/// ```ignore
+/// fluent_typed_macros::l10n!("locales", default_language = "en-gb");
+/// ```
+///
+/// The ftl of all languages is embedded in the generated code, and the ftl
+/// files are tracked so that the code is regenerated when they change. Note
+/// that added ftl files are only picked up when the crate is recompiled.
+///
+/// There is no stable way for a procedural macro to emit warnings, so messages
+/// that are not present in all locales and messages with different signatures
+/// fail the compilation at the macro call. Either can be accepted with:
+///
+/// ```ignore
+/// fluent_typed_macros::l10n!("locales", missing_messages = "ignore", signature_mismatches = "ignore");
+/// ```
+///
+/// `L10n::langneg()` is generated as with the default features of
+/// fluent-typed, and can be left out when its `langneg` feature is disabled:
+///
+/// ```ignore
+/// fluent_typed_macros::l10n!("locales", langneg = false);
+/// ```
+#[proc_macro]
+pub fn l10n(input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(input as Args);
+    match generate(&args) {
+        Ok(code) => code,
+        Err(e) => syn::Error::new(args.folder.span(), e)
+            .to_compile_error()
+            .into(),
+    }
+}
+
+struct Args {
+    folder: LitStr,
+    default_language: Option<LitStr>,
+    missing_messages: ValidationPolicy,
+    signature_mismatches: ValidationPolicy,
+    langneg: bool,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let folder = input.parse()?;
+        let mut default_language = None;
+        let mut missing_messages = ValidationPolicy::Error;
+        let mut signature_mismatches = ValidationPolicy::Error;
+        let mut langneg = true;
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            match key.to_string().as_str() {
+                "default_language" => default_language = Some(input.parse()?),
+                "missing_messages" => missing_messages = policy(input.parse()?)?,
+                "signature_mismatches" => signature_mismatches = policy(input.parse()?)?,
+                "langneg" => langneg = input.parse::<LitBool>()?.value,
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        "Unknown option, expected `default_language`, `missing_messages`, `signature_mismatches` or `langneg`",
+                    ));
+                }
+            }
+        }
+        Ok(Self {
+            folder,
+            default_language,
+            missing_messages,
+            signature_mismatches,
+            langneg,
+        })
+    }
+}
+
+/// The validation policy of the macro, where the warnings of a build
+/// script are errors, since they can't be emitted.
+fn policy(value: LitStr) -> syn::Result<ValidationPolicy> {
+    match value.value().as_str() {
+        "error" => Ok(ValidationPolicy::Error),
+        "ignore" => Ok(ValidationPolicy::Ignore),
+        _ => Err(syn::Error::new(
+            value.span(),
+            "Unknown policy, expected \"error\" or \"ignore\"",
+        )),
+    }
+}
+
+fn generate(args: &Args) -> Result<TokenStream, String> {
+    let root = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| "CARGO_MANIFEST_DIR is not set".to_string())?;
+    let folder = Path::new(&root).join(args.folder.value());
+    let folder = folder
+        .to_str()
+        .ok_or_else(|| format!("Invalid locales folder '{folder:?}'"))?;
+
+    let mut options = BuildOptions::default()
+        .with_locales_folder(folder)
+        .with_ftl_output(FtlOutputOptions::inline())
+        .with_missing_messages(args.missing_messages)
+        .with_signature_mismatches(args.signature_mismatches)
+        .without_format();
+    if let Some(lang) = &args.default_language {
+        options = options.with_default_language(&lang.value());
+    }
+    // the macro's own fluent-typed is built without the langneg feature
+    options = if args.langneg {
+        options.with_langneg()
+    } else {
+        options.without_langneg()
+    };
+    let builder = Builder::load(options)?;
+    builder.validate()?;
+
+    let mut code = builder.generate_code()?;
+    for file in builder.ftl_files() {
+        code.push_str(&format!("\nconst _: &[u8] = include_bytes!({:?});", file));
+    }
+    code.parse()
+        .map_err(|e| format!("Could not parse the generated code: {e}"))
+}
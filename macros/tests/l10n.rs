@@ -0,0 +1,34 @@
+#[allow(dead_code)]
+mod l10n {
+    fluent_typed_macros::l10n!("../src/tests/test_locales_args");
+}
+
+#[allow(dead_code)]
+mod missing {
+    fluent_typed_macros::l10n!(
+        "../src/tests/test_locales_missing_msg",
+        default_language = "en-gb",
+        missing_messages = "ignore",
+    );
+}
+
+use l10n::{L10n, L10nLanguage};
+
+#[test]
+fn test_l10n_macro() {
+    let en: L10nLanguage = L10n::En.load();
+    assert_eq!(en.msg_hello("Anna"), "Hello \u{2068}Anna\u{2069}!");
+    assert_eq!(L10n::De.load().msg_login(), "Anmelden");
+    assert_eq!(L10n::De.language_name(), "Deutsch");
+}
+
+#[test]
+fn test_l10n_macro_langneg() {
+    assert_eq!(L10n::langneg("de-CH, en;q=0.5"), L10n::De);
+    assert_eq!(L10n::langneg("fr"), L10n::En);
+}
+
+#[test]
+fn test_l10n_macro_ignored_missing_messages() {
+    assert!(missing::L10n::iter().count() > 1);
+}
@@ -15,12 +15,15 @@ fn main() -> ExitCode {
 
 fn try_main() -> Result<(), String> {
     let multi_opts = BuildOptions::default()
-        .with_ftl_output(FtlOutputOptions::MultiFile {
-            output_ftl_folder: "gen/multi/".to_string(),
-        })
+        .with_ftl_output(FtlOutputOptions::multi_file("gen/multi/"))
         .with_output_file_path("src/multi_l10n.rs");
     try_build_from_locales_folder(multi_opts)?;
 
+    let embedded_opts = BuildOptions::default()
+        .with_ftl_output(FtlOutputOptions::multi_file_embedded("gen/embedded/"))
+        .with_output_file_path("src/embedded_l10n.rs");
+    try_build_from_locales_folder(embedded_opts)?;
+
     let single_opts = BuildOptions::default()
         .with_ftl_output(FtlOutputOptions::single_file("gen/translations.ftl"))
         .with_output_file_path("src/single_l10n.rs");
@@ -38,5 +41,10 @@ fn try_main() -> Result<(), String> {
     let single_gzip_opts = BuildOptions::default()
         .with_ftl_output(ftl_opts)
         .with_output_file_path("src/single_gzip_l10n.rs");
-    try_build_from_locales_folder(single_gzip_opts)
+    try_build_from_locales_folder(single_gzip_opts)?;
+
+    let out_dir_opts = BuildOptions::default()
+        .with_output_file_path("out_dir_l10n.rs")
+        .with_out_dir();
+    try_build_from_locales_folder(out_dir_opts)
 }
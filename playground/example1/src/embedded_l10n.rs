@@ -0,0 +1,217 @@
+// This file is generated. Do not edit it manually.
+use fluent_typed::prelude::*;
+use std::{
+    fmt::Display,
+    ops::{Deref, Range},
+    slice::Iter,
+    str::FromStr,
+};
+
+static EN_FTL: &[u8] = include_bytes!("../gen/embedded/en.ftl");
+static FR_FTL: &[u8] = include_bytes!("../gen/embedded/fr.ftl");
+static LANGUAGES: [std::sync::OnceLock<L10nLanguage>; 2] =
+    [const { std::sync::OnceLock::new() }; 2];
+
+static ALL_LANGS: [L10n; 2] = [
+    // languages as an array
+    L10n::En,
+    L10n::Fr,
+];
+static MESSAGE_SIGNATURES: &[MessageSignature] = &[
+    MessageSignature::msg("language-name", &[]),
+    MessageSignature::msg("greeting", &["gender"]),
+    MessageSignature::msg("enter-details", &[]),
+];
+
+static EN: LanguageIdentifier = langid!("en");
+static FR: LanguageIdentifier = langid!("fr");
+
+/// The languages that have translations available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum L10n {
+    #[default]
+    En,
+    Fr,
+}
+
+impl FromStr for L10n {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "en" => Ok(Self::En),
+            "fr" => Ok(Self::Fr),
+            _ => Err(format!("Unknown language: {}", s)),
+        }
+    }
+}
+
+impl Deref for L10n {
+    type Target = str;
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::En => "en",
+            Self::Fr => "fr",
+        }
+    }
+}
+
+impl AsRef<LanguageIdentifier> for L10n {
+    fn as_ref(&self) -> &LanguageIdentifier {
+        match self {
+            Self::En => &EN,
+            Self::Fr => &FR,
+        }
+    }
+}
+
+impl AsRef<str> for L10n {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl Display for L10n {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.deref())
+    }
+}
+
+/// The index of the language in [L10nLanguageVec].
+impl From<L10n> for usize {
+    fn from(lang: L10n) -> Self {
+        lang as usize
+    }
+}
+
+impl L10n {
+    pub fn iter() -> Iter<'static, L10n> {
+        ALL_LANGS.iter()
+    }
+
+    /// The language name as defined in the ftl message "language-name".
+    pub fn language_name(&self) -> &'static str {
+        match self {
+            Self::En => "English",
+            Self::Fr => "Français",
+        }
+    }
+
+    /// Negotiate the best language to use based on the `Accept-Language` header.
+    ///
+    /// Falls back to the default language if none of the languages in the header are available.
+    pub fn langneg(accept_language: &str) -> L10n {
+        negotiate_languages(accept_language, &ALL_LANGS)
+    }
+
+    /// The name of the ftl file for the language, which is relative
+    /// to the ftl output folder and can be used as a relative url.
+    pub fn ftl_file_name(&self) -> &'static str {
+        match self {
+            Self::En => "en.ftl",
+            Self::Fr => "fr.ftl",
+        }
+    }
+
+    /// Load a L10nLanguage from its ftl file in the given folder.
+    pub fn load_from_folder(
+        &self,
+        folder: impl AsRef<std::path::Path>,
+    ) -> Result<L10nLanguage, String> {
+        let path = folder.as_ref().join(self.ftl_file_name());
+        let bytes = std::fs::read(&path)
+            .map_err(|e| format!("Could not read ftl file '{}': {e}", path.display()))?;
+        L10nLanguage::new(self, &bytes)
+    }
+
+    /// Load a L10nLanguage with the provided fetch function, which gets the
+    /// [L10n::ftl_file_name] and returns a future with the content of the file.
+    ///
+    /// This is typically used client-side to download only the language that is used.
+    pub async fn load_with<F, Fut, E>(&self, fetch: F) -> Result<L10nLanguage, String>
+    where
+        F: FnOnce(&'static str) -> Fut,
+        Fut: std::future::Future<Output = Result<Vec<u8>, E>>,
+        E: Display,
+    {
+        let name = self.ftl_file_name();
+        let bytes = fetch(name)
+            .await
+            .map_err(|e| format!("Could not fetch ftl file '{name}': {e}"))?;
+        L10nLanguage::new(self, &bytes)
+    }
+
+    /// The embedded ftl of the language.
+    pub fn ftl(&self) -> &'static [u8] {
+        match self {
+            Self::En => EN_FTL,
+            Self::Fr => FR_FTL,
+        }
+    }
+
+    /// Load a L10nLanguage from the embedded ftl.
+    pub fn load(&self) -> L10nLanguage {
+        L10nLanguage::new_embedded(self, self.ftl()).unwrap()
+    }
+
+    /// The L10nLanguage, which is loaded from the embedded ftl the first
+    /// time that it is used and then shared.
+    pub fn get(&self) -> &'static L10nLanguage {
+        LANGUAGES[*self as usize].get_or_init(|| self.load())
+    }
+}
+
+/// A thin wrapper around the Fluent messages for one language.
+///
+/// It provides functions for each message that was found in
+/// all the languages at build time.
+pub struct L10nLanguage(ConcurrentL10nBundle);
+
+impl L10nLanguage {
+    /// Load the L10n resources for the given language. The language
+    /// has to be a valid LanguageIdentifier or otherwise
+    /// an error is returned.
+    ///
+    /// The bytes are expected to be the contents of a .ftl file
+    pub fn new(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, String> {
+        Ok(Self(L10nBundle::new(lang, bytes)?))
+    }
+
+    /// Load the L10n resources from the ftl that the code was generated from.
+    #[allow(dead_code)]
+    fn new_embedded(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, String> {
+        Ok(Self(L10nBundle::new_embedded(lang, bytes)?))
+    }
+
+    /// Same as [L10nLanguage::new], but also verifies that the ftl has all
+    /// the messages, attributes and variables that the code was generated
+    /// for, which is useful for ftl files that are loaded at runtime.
+    ///
+    /// Returns the discrepancies that were found.
+    #[allow(dead_code)]
+    pub fn new_verified(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, Vec<String>> {
+        let language = Self::new(lang, bytes).map_err(|e| vec![e])?;
+        let errors = language.0.verify(MESSAGE_SIGNATURES);
+        if errors.is_empty() {
+            Ok(language)
+        } else {
+            Err(errors)
+        }
+    }
+
+    #[allow(unused)]
+    /// en: `English`
+    pub fn msg_language_name(&self) -> String {
+        self.0.msg("language-name", None).unwrap()
+    }
+    /// en: `{ $gender -> *[any] Welcome! }`
+    pub fn msg_greeting<'a, F0: Into<FluentValue<'a>>>(&self, gender: F0) -> String {
+        let mut args = FluentArgs::new();
+        args.set("gender", gender);
+        self.0.msg("greeting", Some(args)).unwrap()
+    }
+    /// en: `"Please enter your details"`
+    pub fn msg_enter_details(&self) -> String {
+        self.0.msg("enter-details", None).unwrap()
+    }
+}
@@ -1,4 +1,9 @@
 #![allow(unused)]
+mod embedded_l10n;
 mod multi_l10n;
 mod single_gzip_l10n;
 mod single_l10n;
+
+mod out_dir_l10n {
+    fluent_typed::include_l10n!("out_dir_l10n.rs");
+}
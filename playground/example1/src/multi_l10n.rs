@@ -12,23 +12,23 @@ static ALL_LANGS: [L10n; 2] = [
     L10n::En,
     L10n::Fr,
 ];
+static MESSAGE_SIGNATURES: &[MessageSignature] = &[
+    MessageSignature::msg("language-name", &[]),
+    MessageSignature::msg("greeting", &["gender"]),
+    MessageSignature::msg("enter-details", &[]),
+];
 
 static EN: LanguageIdentifier = langid!("en");
 static FR: LanguageIdentifier = langid!("fr");
 
 /// The languages that have translations available.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum L10n {
+    #[default]
     En,
     Fr,
 }
 
-impl Default for L10n {
-    fn default() -> Self {
-        Self::En
-    }
-}
-
 impl FromStr for L10n {
     type Err = String;
 
@@ -72,6 +72,13 @@ impl Display for L10n {
     }
 }
 
+/// The index of the language in [L10nLanguageVec].
+impl From<L10n> for usize {
+    fn from(lang: L10n) -> Self {
+        lang as usize
+    }
+}
+
 impl L10n {
     pub fn iter() -> Iter<'static, L10n> {
         ALL_LANGS.iter()
@@ -91,6 +98,43 @@ impl L10n {
     pub fn langneg(accept_language: &str) -> L10n {
         negotiate_languages(accept_language, &ALL_LANGS)
     }
+
+    /// The name of the ftl file for the language, which is relative
+    /// to the ftl output folder and can be used as a relative url.
+    pub fn ftl_file_name(&self) -> &'static str {
+        match self {
+            Self::En => "en.ftl",
+            Self::Fr => "fr.ftl",
+        }
+    }
+
+    /// Load a L10nLanguage from its ftl file in the given folder.
+    pub fn load_from_folder(
+        &self,
+        folder: impl AsRef<std::path::Path>,
+    ) -> Result<L10nLanguage, String> {
+        let path = folder.as_ref().join(self.ftl_file_name());
+        let bytes = std::fs::read(&path)
+            .map_err(|e| format!("Could not read ftl file '{}': {e}", path.display()))?;
+        L10nLanguage::new(self, &bytes)
+    }
+
+    /// Load a L10nLanguage with the provided fetch function, which gets the
+    /// [L10n::ftl_file_name] and returns a future with the content of the file.
+    ///
+    /// This is typically used client-side to download only the language that is used.
+    pub async fn load_with<F, Fut, E>(&self, fetch: F) -> Result<L10nLanguage, String>
+    where
+        F: FnOnce(&'static str) -> Fut,
+        Fut: std::future::Future<Output = Result<Vec<u8>, E>>,
+        E: Display,
+    {
+        let name = self.ftl_file_name();
+        let bytes = fetch(name)
+            .await
+            .map_err(|e| format!("Could not fetch ftl file '{name}': {e}"))?;
+        L10nLanguage::new(self, &bytes)
+    }
 }
 
 /// A thin wrapper around the Fluent messages for one language.
@@ -109,15 +153,40 @@ impl L10nLanguage {
         Ok(Self(L10nBundle::new(lang, bytes)?))
     }
 
+    /// Load the L10n resources from the ftl that the code was generated from.
+    #[allow(dead_code)]
+    fn new_embedded(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, String> {
+        Ok(Self(L10nBundle::new_embedded(lang, bytes)?))
+    }
+
+    /// Same as [L10nLanguage::new], but also verifies that the ftl has all
+    /// the messages, attributes and variables that the code was generated
+    /// for, which is useful for ftl files that are loaded at runtime.
+    ///
+    /// Returns the discrepancies that were found.
+    #[allow(dead_code)]
+    pub fn new_verified(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, Vec<String>> {
+        let language = Self::new(lang, bytes).map_err(|e| vec![e])?;
+        let errors = language.0.verify(MESSAGE_SIGNATURES);
+        if errors.is_empty() {
+            Ok(language)
+        } else {
+            Err(errors)
+        }
+    }
+
     #[allow(unused)]
+    /// en: `English`
     pub fn msg_language_name(&self) -> String {
         self.0.msg("language-name", None).unwrap()
     }
+    /// en: `{ $gender -> *[any] Welcome! }`
     pub fn msg_greeting<'a, F0: Into<FluentValue<'a>>>(&self, gender: F0) -> String {
         let mut args = FluentArgs::new();
         args.set("gender", gender);
         self.0.msg("greeting", Some(args)).unwrap()
     }
+    /// en: `"Please enter your details"`
     pub fn msg_enter_details(&self) -> String {
         self.0.msg("enter-details", None).unwrap()
     }
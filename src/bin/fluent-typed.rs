@@ -0,0 +1,194 @@
+use clap::{Parser, Subcommand, ValueEnum};
+use fluent_typed::{BuildOptions, Builder, FtlOutputOptions, OutputMode};
+use std::process::ExitCode;
+
+/// Validates fluent locale folders and generates type-safe access code.
+#[derive(Parser)]
+#[command(name = "fluent-typed", version)]
+struct Cli {
+    /// The configuration file. Defaults to `fluent-typed.toml` or the
+    /// `[package.metadata.fluent-typed]` table of `Cargo.toml` in the
+    /// current folder, when present.
+    #[arg(long, global = true)]
+    config: Option<String>,
+
+    /// The folder containing the `<lang-id>/<resource-name>.ftl` files
+    /// [default: locales]
+    #[arg(long, global = true)]
+    locales: Option<String>,
+
+    /// The default language, which has to be present in the locales [default: en]
+    #[arg(long, global = true)]
+    default_language: Option<String>,
+
+    /// Strip comments and the messages without generated functions from the
+    /// emitted ftl. The report then shows the saved bytes.
+    #[arg(long, global = true)]
+    strip_ftl: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Checks that all messages are present in all languages with the same
+    /// signature, and exits with an error otherwise.
+    Check,
+    /// Generates the rust file and the ftl files, as done in a build script.
+    Generate(GenerateArgs),
+    /// Prints a summary of the languages and messages.
+    Report,
+    /// Lists the keys of the messages found in all languages.
+    ListKeys,
+}
+
+#[derive(clap::Args)]
+struct GenerateArgs {
+    /// The path of the generated rust file [default: src/l10n.rs]
+    #[arg(long)]
+    output: Option<String>,
+
+    /// The path of the single ftl file with all languages [default: gen/translations.ftl]
+    #[arg(long)]
+    ftl_file: Option<String>,
+
+    /// Write one ftl file per language into this folder instead of a single file.
+    #[arg(long, conflicts_with = "ftl_file")]
+    ftl_folder: Option<String>,
+
+    /// Embed the ftl files of the --ftl-folder in the generated code.
+    #[arg(long, requires = "ftl_folder")]
+    embed: bool,
+
+    /// What the generated message functions return [default: string]
+    #[arg(long, value_enum)]
+    output_mode: Option<Mode>,
+
+    /// Return the text of plain text messages without formatting them.
+    #[arg(long)]
+    const_text: bool,
+
+    /// Return Cow<str> from the message functions, which borrows plain text
+    /// messages from the bundle.
+    #[arg(long)]
+    cow_strings: bool,
+
+    /// Also generate functions with this prefix that write the messages into a
+    /// fmt::Write.
+    #[arg(long)]
+    write_prefix: Option<String>,
+
+    /// Make the languages Send + Sync with the concurrent memoizer.
+    #[arg(long)]
+    concurrent: bool,
+
+    /// Don't generate L10n::langneg, which requires the langneg feature.
+    #[arg(long)]
+    no_langneg: bool,
+
+    /// Don't format the generated file with rustfmt.
+    #[arg(long)]
+    no_format: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Mode {
+    String,
+    Pattern,
+    Both,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<ExitCode, String> {
+    let mut options = match &cli.config {
+        Some(file) => BuildOptions::from_config_file(file)?,
+        None => BuildOptions::from_package_config(".")?,
+    };
+    if let Some(folder) = &cli.locales {
+        options = options.with_locales_folder(folder);
+    }
+    if cli.strip_ftl {
+        options = options.with_strip_ftl();
+    }
+    if let Some(lang) = &cli.default_language {
+        options = options.with_default_language(lang);
+    }
+
+    if let Command::Generate(args) = &cli.command {
+        if let Some(output) = &args.output {
+            options = options.with_output_file_path(output);
+        }
+        if let Some(mode) = args.output_mode {
+            options = options.with_output_mode(match mode {
+                Mode::String => OutputMode::default(),
+                Mode::Pattern => OutputMode::default_pattern(),
+                Mode::Both => OutputMode::default_both(),
+            });
+        }
+        if let Some(folder) = &args.ftl_folder {
+            options = options.with_ftl_output(if args.embed {
+                FtlOutputOptions::multi_file_embedded(folder)
+            } else {
+                FtlOutputOptions::multi_file(folder)
+            });
+        } else if let Some(file) = &args.ftl_file {
+            options = options.with_ftl_output(FtlOutputOptions::single_file(file));
+        }
+        if args.const_text {
+            options = options.with_const_text();
+        }
+        if args.cow_strings {
+            options = options.with_cow_strings();
+        }
+        if let Some(prefix) = &args.write_prefix {
+            options = options.with_write_prefix(prefix);
+        }
+        if args.concurrent {
+            options = options.with_concurrent();
+        }
+        if args.no_langneg {
+            options = options.without_langneg();
+        }
+        if args.no_format {
+            options = options.without_format();
+        }
+    }
+
+    let builder = Builder::load(options)?;
+
+    match cli.command {
+        Command::Check => {
+            let warnings = builder.validate()?;
+            for warn in &warnings {
+                eprintln!("warning: {warn}");
+            }
+            if !warnings.is_empty() {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Generate(_) => {
+            for warn in builder.validate()? {
+                eprintln!("warning: {warn}");
+            }
+            builder.write_files()?;
+        }
+        Command::Report => println!("{}", builder.report()),
+        Command::ListKeys => {
+            for key in builder.message_keys() {
+                println!("{key}");
+            }
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
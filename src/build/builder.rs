@@ -1,19 +1,49 @@
-use super::{Analyzed, BuildOptions, LangBundle, Message, r#gen::generate, typed::Id};
-use std::{collections::HashSet, fs};
+use super::utils::Traversable;
+use super::{
+    Analyzed, BuildOptions, LangBundle, Message, ValidationPolicy, r#gen::generate,
+    strip::strip_ftl, typed::Id,
+};
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+};
 
+/// Loads the locales and generates the rust code and ftl files, as done by
+/// [crate::build_from_locales_folder].
 pub struct Builder {
     options: BuildOptions,
     langbundles: Vec<LangBundle>,
 }
 
 impl Builder {
-    pub fn load(options: BuildOptions) -> Result<Self, String> {
+    pub fn load(mut options: BuildOptions) -> Result<Self, String> {
+        if options.out_dir {
+            let out_dir = std::env::var("OUT_DIR").map_err(|_| {
+                "OUT_DIR is not set, the out_dir option can only be used in build scripts"
+                    .to_string()
+            })?;
+            options.move_into(&out_dir);
+        }
         let folder = &options.locales_folder;
-        println!("cargo::rerun-if-changed={folder}");
-
-        let mut langbundles = from_locales_folder(folder)
+        let mut langbundles = from_locales_folder(folder, None)
             .map_err(|e| format!("Could not read locales folder '{folder}': {e:?}"))?;
 
+        for additional in &options.additional_locales {
+            let folder = &additional.folder;
+            let bundles = from_locales_folder(folder, additional.resource_prefix.as_deref())
+                .map_err(|e| format!("Could not read locales folder '{folder}': {e:?}"))?;
+            for bundle in bundles {
+                match langbundles
+                    .iter_mut()
+                    .find(|lb| lb.language_id == bundle.language_id)
+                {
+                    Some(existing) => existing.merge(bundle)?,
+                    None => langbundles.push(bundle),
+                }
+            }
+        }
+
         langbundles.sort_by_cached_key(|lb| lb.language_id.clone());
 
         Ok(Self {
@@ -36,31 +66,36 @@ impl Builder {
     }
 
     pub fn generate(&self) -> Result<(), String> {
-        let analyzed = Analyzed::from(&self.langbundles);
-
-        for warn in analyzed.missing_messages {
-            println!("cargo::warning={warn}");
-        }
-        for warn in analyzed.signature_mismatches {
+        for warn in self.validate()? {
             println!("cargo::warning={warn}");
         }
+        self.write_files()
+    }
 
-        let messages = &self.messages(&analyzed.common);
-        let generated = generate(&self.options, &self.langbundles, messages)?
-            .replace("    ", &self.options.indentation);
+    /// Generates and writes the rust and ftl files, like [Builder::generate]
+    /// but without printing the warnings as cargo instructions.
+    pub fn write_files(&self) -> Result<(), String> {
+        let generated = self.generate_code()?;
 
         let output_file_path = &self.options.output_file_path;
-        if let Some(current_file) = fs::read_to_string(output_file_path).ok() {
-            if current_file == generated {
-                return Ok(());
-            }
+        if let Ok(current_file) = fs::read_to_string(output_file_path)
+            && current_file == generated
+        {
+            return Ok(());
         }
 
+        if let Some(folder) = Path::new(output_file_path).parent()
+            && !folder.as_os_str().is_empty()
+        {
+            fs::create_dir_all(folder)
+                .map_err(|e| format!("Could not create folder '{folder:?}': {e:?}"))?;
+        }
         fs::write(output_file_path, generated)
             .map_err(|e| format!("Could not write rust file '{output_file_path}': {e:?}"))?;
 
         if self.options.format {
             let status = std::process::Command::new("rustfmt")
+                .args(["--edition", "2021"])
                 .arg(output_file_path)
                 .status()
                 .map_err(|e| format!("Could not run rustfmt: {e:?}"))?;
@@ -72,6 +107,167 @@ impl Builder {
         Ok(())
     }
 
+    /// The messages that are not present in all locales and the messages
+    /// with different signatures in different locales.
+    pub fn warnings(&self) -> Vec<String> {
+        let analyzed = Analyzed::from(&self.langbundles);
+        let mut warnings = analyzed.missing_messages;
+        warnings.extend(analyzed.signature_mismatches);
+        warnings
+    }
+
+    /// Applies the validation policies of the options. Returns the problems
+    /// that should be reported as warnings, or an error with the problems
+    /// that should fail the build.
+    pub fn validate(&self) -> Result<Vec<String>, String> {
+        let analyzed = Analyzed::from(&self.langbundles);
+        let mut warnings = Vec::new();
+        let mut errors = Vec::new();
+        for (policy, problems) in [
+            (self.options.missing_messages, analyzed.missing_messages),
+            (
+                self.options.signature_mismatches,
+                analyzed.signature_mismatches,
+            ),
+        ] {
+            match policy {
+                ValidationPolicy::Warn => warnings.extend(problems),
+                ValidationPolicy::Error => errors.extend(problems),
+                ValidationPolicy::Ignore => {}
+            }
+        }
+        if errors.is_empty() {
+            Ok(warnings)
+        } else {
+            Err(errors.join("\n"))
+        }
+    }
+
+    /// Generates the rust code without writing it to the output file. The
+    /// ftl files are still generated as configured by the ftl output options.
+    pub fn generate_code(&self) -> Result<String, String> {
+        let analyzed = Analyzed::from(&self.langbundles);
+        let messages = &self.messages(&analyzed.common);
+        Ok(generate(&self.options, &self.langbundles, messages)?
+            .replace("    ", &self.options.indentation))
+    }
+
+    /// The keys of the messages found in all locales, as written in the ftl
+    /// files, together with their variables.
+    pub fn message_keys(&self) -> Vec<String> {
+        let analyzed = Analyzed::from(&self.langbundles);
+        let mut keys = self
+            .messages(&analyzed.common)
+            .into_iter()
+            .map(|msg| {
+                let attr = msg
+                    .id
+                    .attribute
+                    .as_ref()
+                    .map(|a| format!(".{a}"))
+                    .unwrap_or_default();
+                let vars = msg
+                    .variables
+                    .iter()
+                    .map(|v| format!(" ${} ({:?})", v.id, v.typ))
+                    .collect::<String>();
+                format!("{}{attr}{vars}", msg.id.message)
+            })
+            .collect::<Vec<_>>();
+        keys.sort();
+        keys
+    }
+
+    /// A summary of the languages and messages, followed by the warnings.
+    pub fn report(&self) -> String {
+        let analyzed = Analyzed::from(&self.langbundles);
+        let langs = self
+            .langbundles
+            .iter()
+            .map(|lb| lb.language_id.as_str())
+            .collect::<Vec<_>>();
+        let mut out = vec![
+            format!("Languages: {} ({})", langs.len(), langs.join(", ")),
+            format!("Messages in all languages: {}", analyzed.common.len()),
+        ];
+        for lb in &self.langbundles {
+            out.push(format!(
+                "{}: {} messages",
+                lb.language_id,
+                lb.messages.len()
+            ));
+        }
+        out.extend(self.strip_report());
+        out.push(format!(
+            "Missing messages: {}",
+            analyzed.missing_messages.len()
+        ));
+        out.push(format!(
+            "Signature mismatches: {}",
+            analyzed.signature_mismatches.len()
+        ));
+        out.extend(analyzed.missing_messages);
+        out.extend(analyzed.signature_mismatches);
+        out.join("\n")
+    }
+
+    /// The bytes saved by [BuildOptions::strip_ftl] for each language, which
+    /// is empty when the ftl isn't stripped.
+    pub fn strip_report(&self) -> Vec<String> {
+        if !self.options.strip_ftl {
+            return Vec::new();
+        }
+        let analyzed = Analyzed::from(&self.langbundles);
+        let messages = self.messages(&analyzed.common);
+        self.langbundles
+            .iter()
+            .map(|lb| match strip_ftl(&lb.ftl, &messages) {
+                Ok(stripped) => {
+                    let saved = lb.ftl.len().saturating_sub(stripped.len());
+                    format!(
+                        "{}: stripped ftl {} bytes, saved {saved} of {} bytes ({}%)",
+                        lb.language_id,
+                        stripped.len(),
+                        lb.ftl.len(),
+                        saved * 100 / lb.ftl.len().max(1)
+                    )
+                }
+                Err(e) => format!("{}: could not strip ftl: {e}", lb.language_id),
+            })
+            .collect()
+    }
+
+    /// The cargo instructions for rerunning the build script when a locales
+    /// folder, an ftl file or the configuration file changes.
+    pub fn rerun_instructions(&self) -> Result<Vec<String>, String> {
+        let mut paths = Vec::new();
+        let roots = std::iter::once(&self.options.locales_folder)
+            .chain(self.options.additional_locales.iter().map(|a| &a.folder));
+        for root in roots {
+            let mut dirs = Path::new(root)
+                .gather_all_dirs()
+                .map_err(|e| format!("Could not read locales folder '{root}': {e:?}"))?;
+            dirs.sort();
+            paths.extend(dirs);
+        }
+        paths.extend(self.ftl_files().map(Path::to_path_buf));
+        paths.extend(self.options.config_file.iter().map(PathBuf::from));
+
+        let mut seen = HashSet::new();
+        Ok(paths
+            .into_iter()
+            .filter(|path| seen.insert(path.clone()))
+            .map(|path| format!("cargo::rerun-if-changed={}", path.display()))
+            .collect())
+    }
+
+    /// The ftl files that the locales were loaded from.
+    pub fn ftl_files(&self) -> impl Iterator<Item = &Path> {
+        self.langbundles
+            .iter()
+            .flat_map(|lb| lb.files.iter().map(PathBuf::as_path))
+    }
+
     fn messages(&self, common: &HashSet<Id>) -> Vec<&Message> {
         let mut added = HashSet::new();
         self.langbundles
@@ -83,7 +279,10 @@ impl Builder {
     }
 }
 
-fn from_locales_folder(folder: &str) -> Result<Vec<LangBundle>, String> {
+fn from_locales_folder(
+    folder: &str,
+    resource_prefix: Option<&str>,
+) -> Result<Vec<LangBundle>, String> {
     let locales_dir = fs::read_dir(folder).map_err(|e| e.to_string())?;
     let mut locales = Vec::new();
     for entry in locales_dir {
@@ -91,8 +290,9 @@ fn from_locales_folder(folder: &str) -> Result<Vec<LangBundle>, String> {
         let path = entry.path();
         if path.is_dir() {
             let lang = path.file_name().unwrap().to_str().unwrap();
-            locales.push(LangBundle::from_folder(&path, lang)?);
+            locales.push(LangBundle::from_folder(&path, lang, resource_prefix)?);
         }
     }
+    locales.sort_by_cached_key(|lb| lb.language_id.clone());
     Ok(locales)
 }
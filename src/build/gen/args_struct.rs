@@ -0,0 +1,98 @@
+use crate::build::r#gen::StrExt;
+use crate::build::options::BuildOptions;
+use crate::build::typed::{Message, VarType, Variable};
+
+impl Message {
+    pub fn uses_args_struct(&self, options: &BuildOptions) -> bool {
+        options
+            .args_struct_above
+            .is_some_and(|count| self.variables.len() > count)
+    }
+
+    fn args_struct_name(&self) -> String {
+        format!("{}Args", self.id.func_name().rust_var_name())
+    }
+
+    /// The args struct type as used in a function signature.
+    pub fn args_struct_type(&self) -> String {
+        let lt = if needs_lifetime(&self.variables) {
+            "<'_>"
+        } else {
+            ""
+        };
+        format!("{}{lt}", self.args_struct_name())
+    }
+
+    pub fn args_struct(&self) -> String {
+        let name = self.args_struct_name();
+        let (lt, from_lt) = if needs_lifetime(&self.variables) {
+            ("<'a>", "'a")
+        } else {
+            ("", "'_")
+        };
+        let fields = self
+            .variables
+            .iter()
+            .map(|var| format!("    pub {}: {},", var.id.rust_id(), field_type(var)))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let args = self
+            .variables
+            .iter()
+            .map(|var| {
+                format!(
+                    r#"        args.set("{}", value.{});"#,
+                    var.id,
+                    var.id.rust_id()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let impl_lt = if lt.is_empty() { "" } else { "<'a>" };
+
+        format!(
+            r#"
+/// The arguments of the {id}.
+pub struct {name}{lt} {{
+{fields}
+}}
+
+impl{impl_lt} From<{name}{lt}> for FluentArgs<{from_lt}> {{
+    fn from(value: {name}{lt}) -> Self {{
+        let mut args = FluentArgs::new();
+{args}
+        args
+    }}
+}}"#,
+            id = self.id,
+        )
+    }
+
+    pub fn args_struct_impl(&self, signature: &str, (msg_fn, attr_fn): (&str, &str)) -> String {
+        let msg_id = &self.id.message;
+        match self.id.attribute.as_ref() {
+            Some(attr_id) => format!(
+                r##"{signature} {{
+        self.0.{attr_fn}("{msg_id}", "{attr_id}", Some(args.into())).unwrap()
+    }}"##
+            ),
+            None => format!(
+                r##"{signature} {{
+        self.0.{msg_fn}("{msg_id}", Some(args.into())).unwrap()
+    }}"##
+            ),
+        }
+    }
+}
+
+fn needs_lifetime(vars: &[Variable]) -> bool {
+    vars.iter().any(|v| v.typ != VarType::Number)
+}
+
+fn field_type(var: &Variable) -> &'static str {
+    match var.typ {
+        VarType::Any => "FluentValue<'a>",
+        VarType::String => "&'a str",
+        VarType::Number => "FluentNumber",
+    }
+}
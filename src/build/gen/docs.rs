@@ -0,0 +1,77 @@
+use crate::build::LangBundle;
+use crate::build::options::{BuildOptions, DocTranslations};
+use crate::build::typed::Message;
+
+impl Message {
+    /// The doc comment of the generated functions: the comment of the message
+    /// followed by its translations, as selected by the options.
+    pub fn doc_lines(&self, options: &BuildOptions, locales: &[LangBundle]) -> String {
+        let mut lines = self
+            .comment
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>();
+
+        let translations = match options.doc_translations {
+            DocTranslations::None => vec![],
+            DocTranslations::DefaultLanguage => locales
+                .iter()
+                .filter(|l| l.language_id == options.default_language)
+                .filter_map(|l| self.value_in(l))
+                .map(|(lang, value)| format!("{lang}: {}", code_span(&value)))
+                .collect(),
+            DocTranslations::AllLanguages => {
+                let rows = locales
+                    .iter()
+                    .filter_map(|l| self.value_in(l))
+                    .map(|(lang, value)| {
+                        format!("| {lang} | {} |", code_span(&value.replace('|', "\\|")))
+                    })
+                    .collect::<Vec<_>>();
+                if rows.is_empty() {
+                    rows
+                } else {
+                    ["| Language | Translation |", "|---|---|"]
+                        .into_iter()
+                        .map(str::to_string)
+                        .chain(rows)
+                        .collect()
+                }
+            }
+        };
+
+        if !lines.is_empty() && !translations.is_empty() {
+            lines.push(String::new());
+        }
+        lines.extend(translations);
+
+        lines
+            .iter()
+            .map(|line| {
+                if line.is_empty() {
+                    "    ///\n".to_string()
+                } else {
+                    format!("    /// {line}\n")
+                }
+            })
+            .collect()
+    }
+
+    fn value_in<'a>(&self, bundle: &'a LangBundle) -> Option<(&'a str, String)> {
+        bundle
+            .messages
+            .iter()
+            .find(|msg| msg.id == self.id)
+            .map(|msg| (bundle.language_id.as_str(), msg.value.clone()))
+    }
+}
+
+/// Puts the value in a markdown code span, on a single line.
+fn code_span(value: &str) -> String {
+    let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    if value.contains('`') {
+        format!("`` {value} ``")
+    } else {
+        format!("`{value}`")
+    }
+}
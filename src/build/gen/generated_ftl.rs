@@ -6,19 +6,50 @@ use std::{
 };
 
 use super::StrExt;
+use crate::Codec;
+
+/// How the data of each language in the single ftl file is compressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    None,
+    /// With a user provided compressor, and decompressed by the user.
+    Custom,
+    /// With a built-in [Codec].
+    Codec(Codec),
+}
 
 pub enum GeneratedFtl {
     SingleFile {
         output_ftl_file: String,
         positions: Vec<(String, Range<usize>)>,
-        compressed: bool,
+        compression: Compression,
+    },
+    MultiFile {
+        output_ftl_folder: String,
+        /// The language and the name of its ftl file in the output folder.
+        files: Vec<(String, String)>,
+        embed: bool,
+    },
+    Inline {
+        content: String,
+        positions: Vec<(String, Range<usize>)>,
     },
-    MultiFile,
 }
 
 impl GeneratedFtl {
-    pub fn include_replacement(&self, rs_path: &str) -> Result<String, String> {
-        Ok(match self {
+    pub fn include_replacement(&self, rs_path: &str, out_dir: bool) -> Result<String, String> {
+        let mut include = match self {
+            Self::SingleFile {
+                output_ftl_file, ..
+            } if out_dir => {
+                let name = Path::new(output_ftl_file)
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .ok_or_else(|| format!("Invalid ftl file name '{output_ftl_file}'"))?;
+                format!(
+                    "static LANG_DATA: &[u8] = include_bytes!(concat!(env!(\"OUT_DIR\"), \"/{name}\"));"
+                )
+            }
             Self::SingleFile {
                 output_ftl_file, ..
             } => {
@@ -28,87 +59,270 @@ impl GeneratedFtl {
 
                 format!("static LANG_DATA: &[u8] = include_bytes!(\"{path}\");")
             }
-            Self::MultiFile => "".to_string(),
-        })
+            Self::MultiFile {
+                output_ftl_folder,
+                files,
+                embed: true,
+            } => {
+                let mut statics = Vec::new();
+                for (lang, name) in files {
+                    let include = if out_dir {
+                        format!("concat!(env!(\"OUT_DIR\"), \"/{name}\")")
+                    } else {
+                        let file = Path::new(output_ftl_folder).join(name);
+                        let path =
+                            relative_path(rs_path, &file.to_string_lossy()).map_err(|e| {
+                                format!("Could not create relative path between ftl and rs: {e}")
+                            })?;
+                        format!("\"{path}\"")
+                    };
+                    statics.push(format!(
+                        "static {}_FTL: &[u8] = include_bytes!({include});",
+                        lang.rust_static_name()
+                    ));
+                }
+                statics.push(format!(
+                    "static LANGUAGES: [std::sync::OnceLock<L10nLanguage>; {0}] = [const {{ std::sync::OnceLock::new() }}; {0}];",
+                    files.len()
+                ));
+                statics.join("\n")
+            }
+            Self::MultiFile { .. } => "".to_string(),
+            Self::Inline { content, .. } => {
+                format!("static LANG_DATA: &[u8] = {content:?}.as_bytes();")
+            }
+        };
+        if let Self::SingleFile {
+            compression: Compression::Codec(codec),
+            ..
+        } = self
+        {
+            // checked at compile time against the features of the fluent-typed in the
+            // [dependencies], which can differ from the ones of the build dependency
+            include.push_str(&format!(
+                "\nconst _: () = assert!(Codec::{codec:?}.is_enabled(), {:?});",
+                codec.missing_feature()
+            ));
+        }
+        Ok(include)
     }
 
     pub fn accessor_replacement(&self) -> String {
         match self {
             Self::SingleFile {
                 positions,
-                compressed,
+                compression,
                 ..
-            } => self.single_file_load_fn(positions, *compressed),
-            Self::MultiFile => "".to_string(),
+            } => self.single_file_load_fn(positions, *compression),
+            Self::MultiFile { files, embed, .. } => multi_file_load_fn(files, *embed),
+            Self::Inline { positions, .. } => {
+                self.single_file_load_fn(positions, Compression::None)
+            }
         }
     }
 
     fn single_file_load_fn(
         &self,
         positions: &[(String, Range<usize>)],
-        compressed: bool,
+        compression: Compression,
     ) -> String {
         let mut out = String::new();
 
         out.push_str(&byte_range_fn(positions));
 
-        let load_fn = if compressed {
-            r#"
+        let load_fn = match compression {
+            Compression::Custom => {
+                r#"
     /// Load a L10nLanguage from the embedded data.
     /// 
-    /// The provided decompressor function is used to decompress the data
-    /// and has to be the same as when the data was generated in the build.rs script.
+    /// The provided decompressor function is used to decompress the data of the
+    /// language and has to match the compressor in the build.rs script.
     pub fn load<D>(&self, decompressor: D) -> Result<L10nLanguage, String>
     where
         D: Fn(&[u8]) -> Result<Vec<u8>, String>,
     {
-        let bytes = decompressor(LANG_DATA)?;
-        L10nLanguage::new(self, &bytes)
+        let bytes = decompressor(&LANG_DATA[self.byte_range()])?;
+        L10nLanguage::new_embedded(self, &bytes)
     }
 "#
-        } else {
-            r#"
+            }
+            Compression::Codec(_) => {
+                r#"
+    /// Load a L10nLanguage from the embedded data, which is decompressed
+    /// with the codec that it was compressed with in the build.rs script.
+    pub fn load(&self) -> Result<L10nLanguage, String> {
+        let bytes = Codec::decompress(&LANG_DATA[self.byte_range()])?;
+        L10nLanguage::new_embedded(self, &bytes)
+    }
+"#
+            }
+            Compression::None => {
+                r#"
     /// Load a L10nLanguage from the embedded data.
     pub fn load(&self) -> L10nLanguage {
         let bytes = LANG_DATA[self.byte_range()].to_vec();
-        L10nLanguage::new(self, &bytes).unwrap()
+        L10nLanguage::new_embedded(self, &bytes).unwrap()
     }
 "#
+            }
         };
 
-        out.push_str(&load_fn);
+        out.push_str(load_fn);
 
-        let load_all_fn = if compressed {
-            r#"
+        let load_all_fn = match compression {
+            Compression::Custom => {
+                r#"
     /// Load all languages (L10nLanguage) from the embedded data.
     /// 
-    /// The provided decompressor function is used to decompress the data
-    /// and has to be the same as when the data was generated in the build.rs script.
-    pub fn load_all<D>(decompressor: D) -> Result<L10nLanguageVec, String>
+    /// The provided decompressor function is used to decompress the data of each
+    /// language and has to match the compressor in the build.rs script.
+    pub fn load_all<D>(decompressor: D) -> Result<L10nAll, String>
     where
         D: Fn(&[u8]) -> Result<Vec<u8>, String>,
     {
-        let bytes = decompressor(LANG_DATA)?;
-        L10nLanguageVec::load(
-            &bytes,
-            Self::iter().map(|lang| (lang, lang.byte_range())),
-        )
+        L10nLanguageVec::try_new(Self::iter().copied(), |lang| lang.load(&decompressor))
+            .map(L10nAll)
     }"#
-        } else {
-            r#"
+            }
+            Compression::Codec(_) => {
+                r#"
+    /// Load all languages (L10nLanguage) from the embedded data, which is decompressed
+    /// with the codec that it was compressed with in the build.rs script.
+    pub fn load_all() -> Result<L10nAll, String> {
+        L10nLanguageVec::try_new(Self::iter().copied(), |lang| lang.load()).map(L10nAll)
+    }"#
+            }
+            Compression::None => {
+                r#"
     /// Load all languages (L10nLanguage) from the embedded data.
-    pub fn load_all() -> L10nLanguageVec {
-        L10nLanguageVec::load(
-            LANG_DATA,
-            Self::iter().map(|lang| (lang, lang.byte_range())),
-        )
-        .unwrap()
+    pub fn load_all() -> L10nAll {
+        L10nAll(L10nLanguageVec::new(Self::iter().copied(), |lang| lang.load()))
     }"#
+            }
         };
 
-        out.push_str(&load_all_fn);
+        out.push_str(load_all_fn);
         out
     }
+
+    /// The container of the languages returned by `L10n::load_all()`, which
+    /// is only generated for the output with all languages in one file.
+    pub fn all_languages_replacement(&self) -> String {
+        match self {
+            Self::SingleFile { .. } | Self::Inline { .. } => r#"
+/// All the languages, as loaded by [L10n::load_all].
+pub struct L10nAll(L10nLanguageVec<L10n, L10nLanguage>);
+
+impl L10nAll {
+    pub fn get(&self, lang: L10n) -> &L10nLanguage {
+        self.0.get(lang)
+    }
+
+    /// The language, if it is one of the loaded languages.
+    pub fn try_get(&self, lang: L10n) -> Option<&L10nLanguage> {
+        self.0.try_get(lang)
+    }
+
+    /// The language with the given language id, if it is one of the languages.
+    pub fn get_by_id(&self, lang: &str) -> Option<&L10nLanguage> {
+        self.0.get_by_id(lang)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (L10n, &L10nLanguage)> {
+        self.0.iter()
+    }
+}"#
+            .to_string(),
+            Self::MultiFile { .. } => String::new(),
+        }
+    }
+
+    /// Whether the generated `L10nLanguage` is shared between threads and
+    /// has to be `Send + Sync`.
+    pub fn is_shared(&self) -> bool {
+        matches!(self, Self::MultiFile { embed: true, .. })
+    }
+}
+
+fn multi_file_load_fn(files: &[(String, String)], embed: bool) -> String {
+    let file_names = files
+        .iter()
+        .map(|(lang, name)| format!("            Self::{} => \"{name}\",", lang.rust_var_name()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let mut out = format!(
+        r#"
+    /// The name of the ftl file for the language, which is relative
+    /// to the ftl output folder and can be used as a relative url.
+    pub fn ftl_file_name(&self) -> &'static str {{
+        match self {{
+{file_names}
+        }}
+    }}
+
+    /// Load a L10nLanguage from its ftl file in the given folder.
+    pub fn load_from_folder(
+        &self,
+        folder: impl AsRef<std::path::Path>,
+    ) -> Result<L10nLanguage, String> {{
+        let path = folder.as_ref().join(self.ftl_file_name());
+        let bytes = std::fs::read(&path)
+            .map_err(|e| format!("Could not read ftl file '{{}}': {{e}}", path.display()))?;
+        L10nLanguage::new(self, &bytes)
+    }}
+
+    /// Load a L10nLanguage with the provided fetch function, which gets the
+    /// [L10n::ftl_file_name] and returns a future with the content of the file.
+    ///
+    /// This is typically used client-side to download only the language that is used.
+    pub async fn load_with<F, Fut, E>(&self, fetch: F) -> Result<L10nLanguage, String>
+    where
+        F: FnOnce(&'static str) -> Fut,
+        Fut: std::future::Future<Output = Result<Vec<u8>, E>>,
+        E: Display,
+    {{
+        let name = self.ftl_file_name();
+        let bytes = fetch(name)
+            .await
+            .map_err(|e| format!("Could not fetch ftl file '{{name}}': {{e}}"))?;
+        L10nLanguage::new(self, &bytes)
+    }}"#
+    );
+    if embed {
+        let ftl_statics = files
+            .iter()
+            .map(|(lang, _)| {
+                format!(
+                    "            Self::{} => {}_FTL,",
+                    lang.rust_var_name(),
+                    lang.rust_static_name()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        out.push_str(&format!(
+            r#"
+
+    /// The embedded ftl of the language.
+    pub fn ftl(&self) -> &'static [u8] {{
+        match self {{
+{ftl_statics}
+        }}
+    }}
+
+    /// Load a L10nLanguage from the embedded ftl.
+    pub fn load(&self) -> L10nLanguage {{
+        L10nLanguage::new_embedded(self, self.ftl()).unwrap()
+    }}
+
+    /// The L10nLanguage, which is loaded from the embedded ftl the first
+    /// time that it is used and then shared.
+    pub fn get(&self) -> &'static L10nLanguage {{
+        LANGUAGES[*self as usize].get_or_init(|| self.load())
+    }}"#
+        ));
+    }
+    out
 }
 
 fn byte_range_fn(positions: &[(String, Range<usize>)]) -> String {
@@ -177,7 +391,7 @@ fn relative(from_path: &Path, to_path: &Path) -> io::Result<PathBuf> {
     let mut to = to_path.components().collect::<VecDeque<_>>();
 
     // Remove common components
-    while let (Some(fr_comp), Some(to_comp)) = (from.get(0), to.get(0)) {
+    while let (Some(fr_comp), Some(to_comp)) = (from.front(), to.front()) {
         if fr_comp != to_comp {
             break;
         }
@@ -1,5 +1,6 @@
+use crate::build::LangBundle;
 use crate::build::r#gen::StrExt;
-use crate::build::options::OutputMode;
+use crate::build::options::BuildOptions;
 use crate::build::typed::{Message, VarType, Variable};
 
 impl Message {
@@ -7,44 +8,93 @@ impl Message {
         let mut out = Vec::new();
         let func_name = self.id.func_name();
         out.push(self.comment_lines());
-        out.push(self.signature(&self.variables, &func_name).with_semicolon());
+        out.push(
+            self.signature(&self.variables, &func_name, "String")
+                .with_semicolon(),
+        );
 
         out.join("\n")
     }
 
-    fn signature(&self, variables: &[Variable], func_name: &str) -> String {
+    fn signature(&self, variables: &[Variable], func_name: &str, ret: &str) -> String {
         if variables.is_empty() {
-            format!(r"    pub fn {func_name}(&self) -> String")
+            format!(r"    pub fn {func_name}(&self) -> {ret}")
         } else {
             let ArgInfo { generic, arg } = args_declaration(variables);
             let lt = lifetime(variables);
-            format!(r"    pub fn {func_name}<{lt}{generic}>(&self, {arg}) -> String")
+            format!(r"    pub fn {func_name}<{lt}{generic}>(&self, {arg}) -> {ret}")
         }
     }
 
-    pub fn implementations(&self, output_mode: &OutputMode) -> String {
+    /// The signature of the function returning a String, or a Cow with
+    /// [BuildOptions::cow_strings], which takes an args struct instead of
+    /// the variables when there are too many of them.
+    fn string_signature(&self, func_name: &str, options: &BuildOptions) -> String {
+        let ret = if options.cow_strings {
+            "Cow<'_, str>"
+        } else {
+            "String"
+        };
+        if self.uses_const_text(options) {
+            format!(r"    pub fn {func_name}(&self) -> Cow<'static, str>")
+        } else if self.uses_args_struct(options) {
+            format!(
+                r"    pub fn {func_name}(&self, args: {}) -> {ret}",
+                self.args_struct_type()
+            )
+        } else {
+            self.signature(&self.variables, func_name, ret)
+        }
+    }
+
+    pub fn implementations(&self, options: &BuildOptions, locales: &[LangBundle]) -> String {
+        let output_mode = &options.output_mode;
         let func_name = self.id.func_name();
+        let docs = self.doc_lines(options, locales);
         let mut out = String::new();
 
         if let Some(prefix) = output_mode.string_prefix() {
-            let signature = self.signature(&self.variables, &format!("{prefix}{func_name}"));
+            let signature = self.string_signature(&format!("{prefix}{func_name}"), options);
             if func_name == "language_name" {
                 out.push_str("    #[allow(unused)]\n");
             }
-            out.push_str(&self.comment_lines());
-            let implementation = if let Some(attr) = self.id.attribute.as_ref() {
-                self.attr_impl(&self.variables, &self.id.message, attr, &signature)
+            out.push_str(&docs);
+            let format_fns = format_fns(options);
+            let implementation = if self.uses_const_text(options) {
+                self.const_text_impl(locales, &signature)
+            } else if self.uses_args_struct(options) {
+                self.args_struct_impl(&signature, format_fns)
+            } else if let Some(attr) = self.id.attribute.as_ref() {
+                self.attr_impl(
+                    &self.variables,
+                    &self.id.message,
+                    attr,
+                    &signature,
+                    format_fns.1,
+                )
             } else {
-                self.func_impl(&self.variables, &self.id.message, &signature)
+                self.func_impl(&self.variables, &self.id.message, &signature, format_fns.0)
             };
             out.push_str(&implementation);
         }
 
+        if let Some(prefix) = options.write_prefix.as_ref() {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            if func_name == "language_name" {
+                out.push_str("    #[allow(unused)]\n");
+            }
+            out.push_str(&docs);
+            let signature = self.write_signature(&format!("{prefix}{func_name}"), options);
+            out.push_str(&self.write_impl(&signature, options));
+        }
+
         if let Some(prefix) = output_mode.pattern_prefix() {
             if !out.is_empty() {
                 out.push('\n');
             }
-            out.push_str(&self.comment_lines());
+            out.push_str(&docs);
             let ptn_signature = format!("    pub fn {prefix}{func_name}(&self) -> Pattern<String>");
             let ptn_impl = if let Some(attr) = self.id.attribute.as_ref() {
                 format!(
@@ -67,17 +117,178 @@ impl Message {
         out
     }
 
+    /// The declarations of the generated functions in the `L10nMessages` trait.
+    pub fn trait_declarations(&self, options: &BuildOptions, locales: &[LangBundle]) -> String {
+        let docs = self.doc_lines(options, locales);
+        self.trait_functions(options)
+            .into_iter()
+            .map(|(signature, _)| format!("{docs}{}", signature.with_semicolon()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The implementation of the `L10nMessages` trait functions, which
+    /// delegate to the functions of `L10nLanguage`.
+    pub fn trait_delegations(&self, options: &BuildOptions) -> String {
+        self.trait_functions(options)
+            .into_iter()
+            .map(|(signature, call)| {
+                format!(
+                    r##"{signature} {{
+        L10nLanguage::{call}
+    }}"##
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn trait_functions(&self, options: &BuildOptions) -> Vec<(String, String)> {
+        let output_mode = &options.output_mode;
+        let func_name = self.id.func_name();
+        let mut out = Vec::new();
+
+        if let Some(prefix) = output_mode.string_prefix() {
+            let name = format!("{prefix}{func_name}");
+            let signature = self
+                .string_signature(&name, options)
+                .replacen("pub fn", "fn", 1);
+            let args = if self.uses_args_struct(options) {
+                ", args".to_string()
+            } else {
+                self.variables
+                    .iter()
+                    .map(|var| format!(", {}", var.id.rust_id()))
+                    .collect::<String>()
+            };
+            out.push((signature, format!("{name}(self{args})")));
+        }
+
+        if let Some(prefix) = options.write_prefix.as_ref() {
+            let name = format!("{prefix}{func_name}");
+            let signature = self
+                .write_signature(&name, options)
+                .replacen("pub fn", "fn", 1);
+            let args = if self.uses_args_struct(options) {
+                ", args".to_string()
+            } else {
+                self.variables
+                    .iter()
+                    .map(|var| format!(", {}", var.id.rust_id()))
+                    .collect::<String>()
+            };
+            out.push((signature, format!("{name}(self, w{args})")));
+        }
+
+        if let Some(prefix) = output_mode.pattern_prefix() {
+            let name = format!("{prefix}{func_name}");
+            out.push((
+                format!("    fn {name}(&self) -> Pattern<String>"),
+                format!("{name}(self)"),
+            ));
+        }
+        out
+    }
+
+    /// The signature of the function that writes the message into a
+    /// `fmt::Write`, with the writer as first argument.
+    fn write_signature(&self, func_name: &str, options: &BuildOptions) -> String {
+        let writer = "w: &mut impl std::fmt::Write";
+        if self.uses_args_struct(options) {
+            format!(
+                r"    pub fn {func_name}(&self, {writer}, args: {}) -> std::fmt::Result",
+                self.args_struct_type()
+            )
+        } else if self.variables.is_empty() {
+            format!(r"    pub fn {func_name}(&self, {writer}) -> std::fmt::Result")
+        } else {
+            let ArgInfo { generic, arg } = args_declaration(&self.variables);
+            let lt = lifetime(&self.variables);
+            format!(
+                r"    pub fn {func_name}<{lt}{generic}>(&self, {writer}, {arg}) -> std::fmt::Result"
+            )
+        }
+    }
+
+    fn write_impl(&self, signature: &str, options: &BuildOptions) -> String {
+        let msg_id = &self.id.message;
+        let (setup, args) = if self.uses_args_struct(options) {
+            (String::new(), "Some(args.into())")
+        } else if self.variables.is_empty() {
+            (String::new(), "None")
+        } else {
+            let args = args_impl(&self.variables);
+            (
+                format!("        let mut args = FluentArgs::new();\n{args}\n"),
+                "Some(args)",
+            )
+        };
+        let call = match self.id.attribute.as_ref() {
+            Some(attr) => format!(r#"self.0.write_attr(w, "{msg_id}", "{attr}", {args})"#),
+            None => format!(r#"self.0.write_msg(w, "{msg_id}", {args})"#),
+        };
+        format!(
+            r##"{signature} {{
+{setup}        {call}
+    }}"##
+        )
+    }
+
+    /// Whether the string function returns the text of the languages where
+    /// the message is plain text without formatting it, see
+    /// [BuildOptions::const_text].
+    fn uses_const_text(&self, options: &BuildOptions) -> bool {
+        options.const_text && !options.hot_reload && self.variables.is_empty()
+    }
+
+    /// Matches the language of the bundle to the texts found at build time,
+    /// and formats the message with the bundle for the other languages and
+    /// for ftl that was loaded at runtime.
+    fn const_text_impl(&self, locales: &[LangBundle], signature: &str) -> String {
+        let msg_id = &self.id.message;
+        let format = match self.id.attribute.as_ref() {
+            Some(attr) => format!(r#"self.0.attr("{msg_id}", "{attr}", None).unwrap()"#),
+            None => format!(r#"self.0.msg("{msg_id}", None).unwrap()"#),
+        };
+        let arms = locales
+            .iter()
+            .filter_map(|lb| {
+                let msg = lb.messages.iter().find(|msg| msg.id == self.id)?;
+                let text = msg.text.as_ref()?;
+                Some(format!(
+                    "            Some(\"{}\") => {text:?},\n",
+                    lb.language_id
+                ))
+            })
+            .collect::<String>();
+        if arms.is_empty() {
+            return format!(
+                r##"{signature} {{
+        Cow::Owned({format})
+    }}"##,
+            );
+        }
+        format!(
+            r##"{signature} {{
+        Cow::Borrowed(match self.0.embedded_lang() {{
+{arms}            _ => return Cow::Owned({format}),
+        }})
+    }}"##,
+        )
+    }
+
     fn attr_impl(
         &self,
         variables: &[Variable],
         msg_id: &str,
         attr_id: &str,
         signature: &str,
+        attr_fn: &str,
     ) -> String {
         if variables.is_empty() {
             format!(
                 r##"{signature} {{
-        self.0.attr("{msg_id}", "{attr_id}", None).unwrap()
+        self.0.{attr_fn}("{msg_id}", "{attr_id}", None).unwrap()
     }}"##,
             )
         } else {
@@ -87,16 +298,16 @@ impl Message {
                 r##"{signature} {{
         let mut args = FluentArgs::new();
 {args}
-        self.0.attr("{msg_id}", "{attr_id}", Some(args)).unwrap()
+        self.0.{attr_fn}("{msg_id}", "{attr_id}", Some(args)).unwrap()
     }}"##,
             )
         }
     }
-    fn func_impl(&self, variables: &[Variable], id: &str, signature: &str) -> String {
+    fn func_impl(&self, variables: &[Variable], id: &str, signature: &str, msg_fn: &str) -> String {
         if variables.is_empty() {
             format!(
                 r##"{signature} {{
-        self.0.msg("{id}", None).unwrap()
+        self.0.{msg_fn}("{id}", None).unwrap()
     }}"##,
             )
         } else {
@@ -106,7 +317,7 @@ impl Message {
                 r##"{signature} {{
         let mut args = FluentArgs::new();
 {args}
-        self.0.msg("{id}", Some(args)).unwrap()
+        self.0.{msg_fn}("{id}", Some(args)).unwrap()
     }}"##,
             )
         }
@@ -121,11 +332,21 @@ impl Message {
     }
 }
 
+/// The functions of the bundle that format a message and an attribute.
+fn format_fns(options: &BuildOptions) -> (&'static str, &'static str) {
+    if options.cow_strings {
+        ("msg_cow", "attr_cow")
+    } else {
+        ("msg", "attr")
+    }
+}
+
 fn lifetime(vars: &[Variable]) -> &'static str {
-    vars.iter()
-        .any(|v| v.typ == VarType::Any)
-        .then_some("'a, ")
-        .unwrap_or_default()
+    if vars.iter().any(|v| v.typ == VarType::Any) {
+        "'a, "
+    } else {
+        Default::default()
+    }
 }
 
 fn args_declaration(vars: &[Variable]) -> ArgInfo {
@@ -0,0 +1,175 @@
+use crate::build::r#gen::StrExt;
+use crate::build::typed::{Message, VarType, Variable};
+
+pub fn message_ids(messages: &[&Message]) -> String {
+    if messages.is_empty() {
+        return String::new();
+    }
+    let variants = messages
+        .iter()
+        .map(|msg| msg.id_variant())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let message_arms = messages
+        .iter()
+        .map(|msg| {
+            format!(
+                "            Self::{} => \"{}\",",
+                msg.id_pattern(),
+                msg.id.message
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let attribute_arms = messages
+        .iter()
+        .map(|msg| {
+            let attr = msg
+                .id
+                .attribute
+                .as_ref()
+                .map(|a| format!("Some(\"{a}\")"))
+                .unwrap_or_else(|| "None".to_string());
+            format!("            Self::{} => {attr},", msg.id_pattern())
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let get_arms = messages
+        .iter()
+        .map(|msg| msg.id_get_arm())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"
+/// Identifies a message (or a message attribute) that was found in all the
+/// languages at build time, together with the arguments it is formatted with.
+///
+/// It doesn't depend on a language, so it can be stored, sent across threads
+/// and formatted later with [L10nLanguage::get] for any language.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessageId {{
+{variants}
+}}
+
+impl MessageId {{
+    /// The id of the message in the ftl files.
+    pub fn message(&self) -> &'static str {{
+        match self {{
+{message_arms}
+        }}
+    }}
+
+    /// The attribute of the message, when the id refers to a message attribute.
+    pub fn attribute(&self) -> Option<&'static str> {{
+        match self {{
+{attribute_arms}
+        }}
+    }}
+}}
+
+impl L10nLanguage {{
+    /// Format the message identified by the [MessageId] in this language.
+    pub fn get(&self, id: &MessageId) -> String {{
+        match id {{
+{get_arms}
+        }}
+    }}
+}}
+
+/// A value that is rendered in the language of the caller, which lets
+/// errors and notifications created without access to a [L10nLanguage]
+/// carry a message that is formatted later.
+pub trait Localize {{
+    fn localize(&self, lang: &L10nLanguage) -> String;
+}}
+
+impl Localize for MessageId {{
+    fn localize(&self, lang: &L10nLanguage) -> String {{
+        lang.get(self)
+    }}
+}}"#
+    )
+}
+
+impl Message {
+    fn id_variant_name(&self) -> String {
+        self.id.func_name().rust_var_name()
+    }
+
+    fn id_variant(&self) -> String {
+        let name = self.id_variant_name();
+        if self.variables.is_empty() {
+            format!("    {name},")
+        } else {
+            let fields = self
+                .variables
+                .iter()
+                .map(|var| format!("{}: {}", var.id.rust_id(), owned_type(var)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("    {name} {{ {fields} }},")
+        }
+    }
+
+    fn id_pattern(&self) -> String {
+        let name = self.id_variant_name();
+        if self.variables.is_empty() {
+            name
+        } else {
+            format!("{name} {{ .. }}")
+        }
+    }
+
+    fn id_get_arm(&self) -> String {
+        let name = self.id_variant_name();
+        let msg_id = &self.id.message;
+        let call = |args: &str| match self.id.attribute.as_ref() {
+            Some(attr) => format!(r#"self.0.attr("{msg_id}", "{attr}", {args}).unwrap()"#),
+            None => format!(r#"self.0.msg("{msg_id}", {args}).unwrap()"#),
+        };
+        if self.variables.is_empty() {
+            return format!("            MessageId::{name} => {},", call("None"));
+        }
+        let fields = self
+            .variables
+            .iter()
+            .map(|var| var.id.rust_id())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let args = self
+            .variables
+            .iter()
+            .map(|var| {
+                let name = var.id.as_str();
+                let id = var.id.rust_id();
+                match var.typ {
+                    VarType::Any | VarType::Number => {
+                        format!(r#"                args.set("{name}", {id}.clone());"#)
+                    }
+                    VarType::String => {
+                        format!(r#"                args.set("{name}", {id}.as_str());"#)
+                    }
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!(
+            r#"            MessageId::{name} {{ {fields} }} => {{
+                let mut args = FluentArgs::new();
+{args}
+                {}
+            }}"#,
+            call("Some(args)")
+        )
+    }
+}
+
+fn owned_type(var: &Variable) -> &'static str {
+    match var.typ {
+        VarType::Any => "FluentValue<'static>",
+        VarType::String => "String",
+        VarType::Number => "FluentNumber",
+    }
+}
@@ -1,19 +1,34 @@
+mod args_struct;
+mod docs;
 mod ext;
 mod generated_ftl;
 mod message;
+mod message_id;
+mod signatures;
 #[allow(dead_code, unused_mut, unused_imports)]
 mod template;
 
-use super::{BuildOptions, LangBundle, Message};
+use super::{BuildOptions, LangBundle, Message, strip::strip_ftl};
 pub use ext::StrExt;
-pub use generated_ftl::GeneratedFtl;
+pub use generated_ftl::{Compression, GeneratedFtl};
 
-pub fn generate<'a>(
+pub fn generate(
     options: &BuildOptions,
     locales: &[LangBundle],
-    messages: &[&'a Message],
+    messages: &[&Message],
 ) -> Result<String, String> {
-    let generated_ftl = options.ftl_output.generate(&locales)?;
+    let ftls = locales
+        .iter()
+        .map(|lb| {
+            let ftl = if options.strip_ftl {
+                strip_ftl(&lb.ftl, messages)?
+            } else {
+                lb.ftl.clone()
+            };
+            Ok((lb.language_id.clone(), ftl))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    let generated_ftl = options.ftl_output.generate(&ftls)?;
 
     let mut langs = locales
         .iter()
@@ -26,7 +41,7 @@ pub fn generate<'a>(
 
     replacements.push((
         "<<placeholder lang_data>>",
-        generated_ftl.include_replacement(&options.output_file_path)?,
+        generated_ftl.include_replacement(&options.output_file_path, options.out_dir)?,
     ));
 
     // ///////////////////////////
@@ -39,12 +54,52 @@ static ALL_LANGS: [L10n; {}] = [
     // languages as an array
 {enum_entries}
 ];"#,
-        langs.iter().count()
+        langs.len()
     );
     replacements.push(("<<placeholder all_langs>>", all_langs));
 
     // ///////////////////////////
-    let enum_lang_ids = if cfg!(feature = "langneg") {
+    let mut signatures = signatures::message_signatures(messages);
+    let concurrent = options.concurrent || generated_ftl.is_shared();
+    let hot_reload_new = "        Ok(Self(HotReloadBundle::new(lang, bytes, env!(\"CARGO_MANIFEST_DIR\"), LOCALES_FOLDERS, MESSAGE_SIGNATURES)?))";
+    if options.hot_reload {
+        signatures = format!(
+            "{}\n{}\n{signatures}",
+            signatures::hot_reload_check(),
+            signatures::locales_folders(options)?
+        );
+    }
+    let (language_struct, language_new) = if options.hot_reload && concurrent {
+        (
+            "pub struct L10nLanguage(ConcurrentHotReloadBundle);",
+            hot_reload_new,
+        )
+    } else if options.hot_reload {
+        ("pub struct L10nLanguage(HotReloadBundle);", hot_reload_new)
+    } else if concurrent {
+        (
+            "pub struct L10nLanguage(ConcurrentL10nBundle);",
+            "        Ok(Self(L10nBundle::new(lang, bytes)?))",
+        )
+    } else {
+        (
+            "pub struct L10nLanguage(L10nBundle);",
+            "        Ok(Self(L10nBundle::new(lang, bytes)?))",
+        )
+    };
+    replacements.push(("<<placeholder message signatures>>", signatures));
+    replacements.push((
+        "<<placeholder language struct>>",
+        language_struct.to_string(),
+    ));
+    replacements.push(("<<placeholder language new>>", language_new.to_string()));
+    replacements.push((
+        "<<placeholder language new embedded>>",
+        language_new.replace("L10nBundle::new(", "L10nBundle::new_embedded("),
+    ));
+
+    // ///////////////////////////
+    let enum_lang_ids = if options.langneg {
         collect(langs.iter(), |lang| {
             format!(
                 "static {}: LanguageIdentifier = langid!(\"{lang}\");",
@@ -56,12 +111,6 @@ static ALL_LANGS: [L10n; {}] = [
     };
     replacements.push(("<<placeholder static enum langid>>", enum_lang_ids));
 
-    // ///////////////////////////
-    let enum_variants = collect(langs.iter(), |lang| {
-        format!("{indent}{},", lang.rust_var_name())
-    });
-    replacements.push(("<<placeholder enum variant>>", enum_variants));
-
     // ///////////////////////////
 
     if !langs.contains(&options.default_language.as_str()) {
@@ -71,12 +120,15 @@ static ALL_LANGS: [L10n; {}] = [
         ));
     }
 
-    let default_lang = format!(
-        "        Self::{}",
-        &options.default_language.rust_var_name()
-    );
-
-    replacements.push(("<<placeholder default lang>>", default_lang));
+    let enum_variants = collect(langs.iter(), |lang| {
+        let default = if *lang == options.default_language {
+            format!("{indent}#[default]\n")
+        } else {
+            String::new()
+        };
+        format!("{default}{indent}{},", lang.rust_var_name())
+    });
+    replacements.push(("<<placeholder enum variant>>", enum_variants));
 
     // ///////////////////////////
     let enum_from_str = collect(langs.iter(), |lang| {
@@ -90,7 +142,7 @@ static ALL_LANGS: [L10n; {}] = [
 
     // ///////////////////////////
 
-    let as_ref_langid = if cfg!(feature = "langneg") {
+    let as_ref_langid = if options.langneg {
         let id_entries = collect(langs.iter(), |lang| {
             format!(
                 "{}Self::{} => &{},",
@@ -171,16 +223,15 @@ static ALL_LANGS: [L10n; {}] = [
 
     // ///////////////////////////
 
-    let langneg_fn = if cfg!(feature = "langneg") {
-        format!(
-            r#"
+    let langneg_fn = if options.langneg {
+        r#"
     /// Negotiate the best language to use based on the `Accept-Language` header.
     ///
     /// Falls back to the default language if none of the languages in the header are available.
-    pub fn langneg(accept_language: &str) -> L10n {{
+    pub fn langneg(accept_language: &str) -> L10n {
         negotiate_languages(accept_language, &ALL_LANGS)
-    }}"#,
-        )
+    }"#
+        .to_string()
     } else {
         String::new()
     };
@@ -194,11 +245,40 @@ static ALL_LANGS: [L10n; {}] = [
     ));
 
     // ///////////////////////////
-    let impls = collect(messages.iter(), |msg| {
-        msg.implementations(&options.output_mode)
-    });
+    replacements.push((
+        "<<placeholder all languages>>",
+        generated_ftl.all_languages_replacement(),
+    ));
+
+    // ///////////////////////////
+    let impls = collect(messages.iter(), |msg| msg.implementations(options, locales));
     replacements.push(("<<message implementations>>", impls));
 
+    // ///////////////////////////
+    let args_structs = messages
+        .iter()
+        .filter(|msg| msg.uses_args_struct(options))
+        .map(|msg| msg.args_struct())
+        .collect::<Vec<_>>()
+        .join("\n");
+    replacements.push(("<<placeholder args structs>>", args_structs));
+
+    // ///////////////////////////
+    let messages_trait = if options.messages_trait {
+        messages_trait(messages, options, locales)
+    } else {
+        String::new()
+    };
+    replacements.push(("<<placeholder messages trait>>", messages_trait));
+
+    // ///////////////////////////
+    let ids = if options.message_ids {
+        message_id::message_ids(messages)
+    } else {
+        String::new()
+    };
+    replacements.push(("<<placeholder message ids>>", ids));
+
     let mut base = do_replace(include_str!("template.rs"), replacements);
     base.push('\n');
 
@@ -207,6 +287,28 @@ static ALL_LANGS: [L10n; {}] = [
     Ok(base)
 }
 
+fn messages_trait(messages: &[&Message], options: &BuildOptions, locales: &[LangBundle]) -> String {
+    let declarations = collect(messages.iter(), |msg| {
+        msg.trait_declarations(options, locales)
+    });
+    let delegations = collect(messages.iter(), |msg| msg.trait_delegations(options));
+    format!(
+        r#"
+/// The functions for all the messages that were found in all the languages
+/// at build time.
+///
+/// It is implemented by [L10nLanguage] and lets code that formats messages
+/// be used with mocks or alternative implementations.
+pub trait L10nMessages {{
+{declarations}
+}}
+
+impl L10nMessages for L10nLanguage {{
+{delegations}
+}}"#
+    )
+}
+
 fn collect<T, F: Fn(T) -> String>(vals: impl Iterator<Item = T>, f: F) -> String {
     vals.map(f).collect::<Vec<_>>().join("\n")
 }
@@ -217,10 +319,12 @@ fn do_replace(base: &str, replacements: Vec<(&str, String)>) -> String {
             if !line.contains("<<") {
                 return Some(line.to_string());
             }
+            if line.contains("<<template only>>") {
+                return None;
+            }
             for (placeholder, replacement) in &replacements {
                 if line.contains(placeholder) {
                     return if replacement.is_empty() {
-                        eprintln!("Empty replacement for placeholder: {}", placeholder);
                         None
                     } else {
                         Some(replacement.to_string())
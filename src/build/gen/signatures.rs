@@ -0,0 +1,64 @@
+use std::path::Path;
+
+use crate::build::options::BuildOptions;
+use crate::build::typed::Message;
+
+/// Fails the compilation of the generated code when the `hot-reload` feature
+/// of the fluent-typed in the `[dependencies]` isn't enabled.
+pub fn hot_reload_check() -> String {
+    r#"const _: () = assert!(
+    hot_reload_enabled(),
+    "BuildOptions::with_hot_reload() requires the 'hot-reload' feature of fluent-typed"
+);"#
+    .to_string()
+}
+
+/// The folders that the locales are read from at runtime, relative to the
+/// package folder when they are inside it, so that the generated code
+/// doesn't depend on where the package is checked out.
+pub fn locales_folders(options: &BuildOptions) -> Result<String, String> {
+    let package = std::env::current_dir().map_err(|e| e.to_string())?;
+    let folders = std::iter::once(&options.locales_folder)
+        .chain(options.additional_locales.iter().map(|a| &a.folder))
+        .map(|folder| {
+            let path = Path::new(folder);
+            if !path.is_dir() {
+                return Err(format!("Could not find locales folder '{folder}'"));
+            }
+            let relative = path.strip_prefix(&package).unwrap_or(path);
+            Ok(format!("{:?}", relative.to_string_lossy()))
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .join(", ");
+
+    Ok(format!("static LOCALES_FOLDERS: &[&str] = &[{folders}];"))
+}
+
+/// The messages and variables that the code was generated for, which the
+/// ftl loaded at runtime is verified against.
+pub fn message_signatures(messages: &[&Message]) -> String {
+    let signatures = messages
+        .iter()
+        .map(|msg| {
+            let variables = msg
+                .variables
+                .iter()
+                .map(|v| format!("\"{}\"", v.id))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let message = &msg.id.message;
+            match msg.id.attribute.as_ref() {
+                Some(attr) => {
+                    format!(r#"    MessageSignature::attr("{message}", "{attr}", &[{variables}]),"#)
+                }
+                None => format!(r#"    MessageSignature::msg("{message}", &[{variables}]),"#),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        r#"static MESSAGE_SIGNATURES: &[MessageSignature] = &[
+{signatures}
+];"#
+    )
+}
@@ -9,21 +9,17 @@ use std::{
 
 static LANG_DATA: &[u8] = include_bytes!("./ftl.bin"); // <<placeholder lang_data>>
 static ALL_LANGS: [L10n; 1] = [L10n::Placeholder]; // <<placeholder all_langs>>
+static MESSAGE_SIGNATURES: &[MessageSignature] = &[]; // <<placeholder message signatures>>
 
 // <<placeholder static enum langid>>
 
 /// The languages that have translations available.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum L10n {
+    #[default] // <<template only>>
     Placeholder, // <<placeholder enum variant>>
 }
 
-impl Default for L10n {
-    fn default() -> Self {
-        Self::Placeholder // <<placeholder default lang>>
-    }
-}
-
 impl FromStr for L10n {
     type Err = String;
 
@@ -57,6 +53,13 @@ impl Display for L10n {
     }
 }
 
+/// The index of the language in [L10nLanguageVec].
+impl From<L10n> for usize {
+    fn from(lang: L10n) -> Self {
+        lang as usize
+    }
+}
+
 impl L10n {
     pub fn iter() -> Iter<'static, L10n> {
         ALL_LANGS.iter()
@@ -70,7 +73,7 @@ impl L10n {
 ///
 /// It provides functions for each message that was found in
 /// all the languages at build time.
-pub struct L10nLanguage(L10nBundle);
+pub struct L10nLanguage(L10nBundle); // <<placeholder language struct>>
 
 impl L10nLanguage {
     /// Load the L10n resources for the given language. The language
@@ -79,8 +82,38 @@ impl L10nLanguage {
     ///
     /// The bytes are expected to be the contents of a .ftl file
     pub fn new(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, String> {
-        Ok(Self(L10nBundle::new(lang, bytes)?))
+        Ok(Self(L10nBundle::new(lang, bytes)?)) // <<placeholder language new>>
+    }
+
+    /// Load the L10n resources from the ftl that the code was generated from.
+    #[allow(dead_code)]
+    fn new_embedded(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, String> {
+        Ok(Self(L10nBundle::new_embedded(lang, bytes)?)) // <<placeholder language new embedded>>
+    }
+
+    /// Same as [L10nLanguage::new], but also verifies that the ftl has all
+    /// the messages, attributes and variables that the code was generated
+    /// for, which is useful for ftl files that are loaded at runtime.
+    ///
+    /// Returns the discrepancies that were found.
+    #[allow(dead_code)]
+    pub fn new_verified(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, Vec<String>> {
+        let language = Self::new(lang, bytes).map_err(|e| vec![e])?;
+        let errors = language.0.verify(MESSAGE_SIGNATURES);
+        if errors.is_empty() {
+            Ok(language)
+        } else {
+            Err(errors)
+        }
     }
 
     // <<message implementations>>
 }
+
+// <<placeholder all languages>>
+
+// <<placeholder args structs>>
+
+// <<placeholder messages trait>>
+
+// <<placeholder message ids>>
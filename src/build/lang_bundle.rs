@@ -4,14 +4,19 @@ use super::Message;
 use fluent_syntax::ast::Resource;
 use fluent_syntax::parser;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug)]
 pub struct LangBundle {
     pub language_name: Option<String>,
     pub language_id: String,
     pub messages: Vec<Message>,
+    /// The ids of all the messages and terms (with their leading `-`),
+    /// together with the resource they are defined in.
+    pub entry_ids: Vec<(String, String)>,
     pub ftl: String,
+    /// The ftl files the bundle was loaded from.
+    pub files: Vec<PathBuf>,
 }
 
 impl LangBundle {
@@ -22,15 +27,25 @@ impl LangBundle {
             language_name: lang_name(&ast),
             language_id: lang.to_string(),
             messages: to_messages(name, &ast)?,
+            entry_ids: entry_ids(name, &ast),
             ftl: ftl.to_string(),
+            files: Vec::new(),
         })
     }
-    pub fn from_folder(folder: &Path, lang: &str) -> Result<Self, String> {
+    /// Loads all the ftl files in the folder. The resource prefix is
+    /// prepended to the resource names.
+    pub fn from_folder(
+        folder: &Path,
+        lang: &str,
+        resource_prefix: Option<&str>,
+    ) -> Result<Self, String> {
         let mut bundle = LangBundle {
             language_name: None,
             language_id: lang.to_string(),
             messages: Vec::new(),
+            entry_ids: Vec::new(),
             ftl: String::new(),
+            files: Vec::new(),
         };
 
         let mut paths = folder
@@ -44,12 +59,15 @@ impl LangBundle {
             let ast = parser::parse(ftl.as_str())
                 .map_err(|e| format!("Could not parse ftl due to: {e:?}"))?;
 
-            if let Some(lang_name) = lang_name(&ast) {
-                if bundle.language_name.is_none() {
-                    bundle.language_name = Some(lang_name);
-                }
+            if let Some(lang_name) = lang_name(&ast)
+                && bundle.language_name.is_none()
+            {
+                bundle.language_name = Some(lang_name);
+            }
+            let mut name = path.file_stem().unwrap().to_str().unwrap().to_string();
+            if let Some(prefix) = resource_prefix {
+                name = format!("{prefix}/{name}");
             }
-            let name = path.file_stem().unwrap().to_str().unwrap().to_string();
 
             bundle.ftl.push_str(&format!(
                 "\n## ########## Resource: {name} ###############\n\n"
@@ -59,9 +77,37 @@ impl LangBundle {
 
             let messages = to_messages(&name, &ast)?;
             bundle.messages.extend(messages);
+            bundle.entry_ids.extend(entry_ids(&name, &ast));
+            bundle.files.push(path);
         }
         Ok(bundle)
     }
+
+    /// Merges the resources of another bundle for the same language into
+    /// this one. Fails when a message or term is defined in both.
+    pub fn merge(&mut self, other: LangBundle) -> Result<(), String> {
+        for (id, resource) in &other.entry_ids {
+            if let Some((_, existing)) = self.entry_ids.iter().find(|(i, _)| i == id) {
+                let kind = if id.starts_with('-') {
+                    "term"
+                } else {
+                    "message"
+                };
+                return Err(format!(
+                    "The {kind} '{id}' is defined in both resource '{existing}' and '{resource}' for language '{}'",
+                    self.language_id
+                ));
+            }
+        }
+        if self.language_name.is_none() {
+            self.language_name = other.language_name;
+        }
+        self.ftl.push_str(&other.ftl);
+        self.messages.extend(other.messages);
+        self.entry_ids.extend(other.entry_ids);
+        self.files.extend(other.files);
+        Ok(())
+    }
 }
 
 fn to_messages(name: &str, ast: &Resource<&str>) -> Result<Vec<Message>, String> {
@@ -76,6 +122,19 @@ fn to_messages(name: &str, ast: &Resource<&str>) -> Result<Vec<Message>, String>
         .collect())
 }
 
+fn entry_ids(name: &str, ast: &Resource<&str>) -> Vec<(String, String)> {
+    use fluent_syntax::ast::Entry;
+    ast.body
+        .iter()
+        .filter_map(|entry| match entry {
+            Entry::Message(m) => Some(m.id.name.to_string()),
+            Entry::Term(t) => Some(format!("-{}", t.id.name)),
+            _ => None,
+        })
+        .map(|id| (id, name.to_string()))
+        .collect()
+}
+
 fn lang_name(ast: &Resource<&str>) -> Option<String> {
     use fluent_syntax::ast::PatternElement::TextElement;
     ast.body
@@ -87,8 +146,8 @@ fn lang_name(ast: &Resource<&str>) -> Option<String> {
                 }
                 let Some(value) = &m.value else { return None };
 
-                if let Some(TextElement { value }) = value.elements.iter().next() {
-                    return Some(value.to_string());
+                if let Some(TextElement { value }) = value.elements.first() {
+                    Some(value.to_string())
                 } else {
                     None
                 }
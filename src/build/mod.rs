@@ -2,14 +2,20 @@ mod builder;
 pub mod r#gen;
 mod lang_bundle;
 pub mod options;
+mod strip;
 pub mod typed;
 mod utils;
 mod validations;
 
 pub use builder::Builder;
 pub use lang_bundle::LangBundle;
-pub use options::{BuildOptions, FtlOutputOptions, OutputMode};
+pub use options::{
+    AdditionalLocales, BuildOptions, Compressor, DocTranslations, FtlOutputOptions, OutputMode,
+    ValidationPolicy,
+};
+use std::collections::HashSet;
 use std::process::ExitCode;
+use std::sync::Mutex;
 pub use typed::Message;
 pub use validations::Analyzed;
 
@@ -54,9 +60,26 @@ pub fn build_from_locales_folder(options: BuildOptions) -> ExitCode {
 }
 
 /// Same as [build_from_locales_folder], but returns result instead of an ExitCode.
+///
+/// The rerun instructions for cargo are only printed once per build script, even
+/// when it builds several outputs from the same locales folder.
 pub fn try_build_from_locales_folder(options: BuildOptions) -> Result<(), String> {
-    let locales = &options.locales_folder;
-    println!("cargo::rerun-if-changed={locales}");
+    static PRINTED: Mutex<Option<HashSet<String>>> = Mutex::new(None);
 
-    Builder::load(options)?.generate()
+    let builder = Builder::load(options)?;
+    let instructions = builder.rerun_instructions()?;
+    {
+        let mut printed = PRINTED.lock().unwrap_or_else(|e| e.into_inner());
+        let printed = printed.get_or_insert_with(HashSet::new);
+        for instruction in instructions {
+            if printed.insert(instruction.clone()) {
+                println!("{instruction}");
+            }
+        }
+    }
+    builder.generate()?;
+    for line in builder.strip_report() {
+        println!("cargo::warning={line}");
+    }
+    Ok(())
 }
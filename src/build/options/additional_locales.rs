@@ -0,0 +1,15 @@
+/// A folder with locales that are merged per language with the ones in the
+/// locales folder, such as shared brand terms and generic UI strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "config",
+    derive(serde::Deserialize),
+    serde(deny_unknown_fields, rename_all = "kebab-case")
+)]
+pub struct AdditionalLocales {
+    /// The folder containing the `<lang-id>/<resource-name>.ftl` files.
+    pub folder: String,
+    /// A prefix for the resource names of the folder, which shows where a
+    /// message comes from in the generated ftl and in error messages.
+    pub resource_prefix: Option<String>,
+}
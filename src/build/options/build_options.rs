@@ -1,5 +1,10 @@
+use std::path::Path;
+
+use super::additional_locales::AdditionalLocales;
+use super::doc_translations::DocTranslations;
 use super::ftl_output_options::FtlOutputOptions;
 use super::output_mode::OutputMode;
+use super::validation_policy::ValidationPolicy;
 
 pub struct BuildOptions {
     /// The path to the folder containing the locales.
@@ -7,6 +12,13 @@ pub struct BuildOptions {
     /// Defaults to "locales".
     pub locales_folder: String,
 
+    /// Additional folders with locales, which are merged per language with
+    /// the ones in the locales folder into a single L10n. It is an error when
+    /// a message is defined in more than one of the folders.
+    ///
+    /// Defaults to none.
+    pub additional_locales: Vec<AdditionalLocales>,
+
     /// The path to the file where the generated code will be written. It is recommended
     /// to use a path inside of `src/` and to include the file in the project so that
     /// you get warnings for unused translation messages.
@@ -39,18 +51,145 @@ pub struct BuildOptions {
     ///
     /// Defaults to OutputMode::String with prefix "msg_".
     pub output_mode: OutputMode,
+
+    /// Whether to generate the `MessageId` enum, which identifies each message
+    /// together with its arguments so that it can be formatted later with
+    /// `L10nLanguage::get` in any language.
+    ///
+    /// Defaults to false.
+    pub message_ids: bool,
+
+    /// Whether to generate the `L10nMessages` trait, which has all the message
+    /// functions and is implemented by `L10nLanguage`. This allows for mocking
+    /// the messages in tests or plugging in alternative implementations.
+    ///
+    /// Note that unused message functions are not reported when the trait
+    /// is generated.
+    ///
+    /// Defaults to false.
+    pub messages_trait: bool,
+
+    /// Messages with more variables than this take a generated `<Message>Args`
+    /// struct with named fields instead of one generic parameter per variable,
+    /// so that arguments of the same type cannot be swapped by mistake.
+    ///
+    /// Defaults to None, which means that args structs are never generated.
+    pub args_struct_above: Option<usize>,
+
+    /// Which translations to add to the documentation of the generated
+    /// message functions, so that the IDE shows what a message says.
+    ///
+    /// Defaults to DocTranslations::DefaultLanguage.
+    pub doc_translations: DocTranslations,
+
+    /// Whether to write the generated rust file and the ftl files into
+    /// the `OUT_DIR` of the build script instead of into the source tree, so
+    /// that builds don't modify the package. The file names are taken from
+    /// `output_file_path` and the ftl output options. The generated code is
+    /// then included with `fluent_typed::include_l10n!()`.
+    ///
+    /// Defaults to false.
+    pub out_dir: bool,
+
+    /// How messages that are not present in all locales are reported.
+    ///
+    /// Defaults to ValidationPolicy::Warn.
+    pub missing_messages: ValidationPolicy,
+
+    /// How messages with different variables in different locales are reported.
+    ///
+    /// Defaults to ValidationPolicy::Warn.
+    pub signature_mismatches: ValidationPolicy,
+
+    /// The configuration file that the options were loaded from, which is
+    /// tracked for changes by the build script.
+    ///
+    /// Defaults to None.
+    pub config_file: Option<String>,
+
+    /// Whether the generated `L10nLanguage` reads the ftl files of its language
+    /// from the locales folders on disk and reloads them when they change. It
+    /// requires the `hot-reload` feature of fluent-typed in the dependencies,
+    /// and is meant for development.
+    ///
+    /// Defaults to false.
+    pub hot_reload: bool,
+
+    /// Whether the emitted ftl is re-serialized without comments and with only
+    /// the messages and terms that the generated functions use, directly or
+    /// through references, which makes the embedded or downloaded ftl smaller.
+    /// The saved bytes of each language are printed as cargo warnings by
+    /// [crate::try_build_from_locales_folder], and shown in the report.
+    ///
+    /// Defaults to false.
+    pub strip_ftl: bool,
+
+    /// Whether the string functions of messages without variables return a
+    /// `Cow<'static, str>` with the text found at build time for the languages
+    /// where the message is plain text, so that these are not formatted by the
+    /// bundle. Other languages, and messages with placeables, are formatted as
+    /// usual. Should not be used when the ftl that is loaded at runtime can
+    /// differ from the locales at build time, and is ignored with hot reload.
+    ///
+    /// Defaults to false.
+    pub const_text: bool,
+
+    /// Whether the string functions return a `Cow<'_, str>` that borrows the
+    /// text from the bundle when the message has no placeables, instead of
+    /// allocating a `String` for every call.
+    ///
+    /// Defaults to false.
+    pub cow_strings: bool,
+
+    /// The prefix of the functions that write the formatted message into a
+    /// `fmt::Write`, such as a template buffer, without allocating a String.
+    /// These are generated in addition to the functions of the output mode.
+    ///
+    /// Defaults to None, which doesn't generate them.
+    pub write_prefix: Option<String>,
+
+    /// Whether the generated `L10nLanguage` uses the concurrent memoizer, so
+    /// that it and the languages of `L10n::load_all()` are `Send + Sync` and
+    /// can be shared between the threads of a server.
+    /// Embedded multi file ftl output is always concurrent.
+    ///
+    /// Defaults to false.
+    pub concurrent: bool,
+
+    /// Whether to generate `L10n::langneg()` and `AsRef<LanguageIdentifier>`
+    /// for `L10n`, which require the `langneg` feature of fluent-typed in the
+    /// `[dependencies]`.
+    ///
+    /// Defaults to whether the `langneg` feature is enabled.
+    pub langneg: bool,
 }
 
 impl Default for BuildOptions {
     fn default() -> Self {
         Self {
             locales_folder: "locales".to_string(),
+            additional_locales: Vec::new(),
             output_file_path: "src/l10n.rs".to_string(),
             ftl_output: Default::default(),
             indentation: "    ".to_string(),
             default_language: "en".to_string(),
             format: true,
             output_mode: OutputMode::default(),
+            message_ids: false,
+            messages_trait: false,
+            args_struct_above: None,
+            doc_translations: DocTranslations::default(),
+            out_dir: false,
+            missing_messages: ValidationPolicy::default(),
+            signature_mismatches: ValidationPolicy::default(),
+            config_file: None,
+            hot_reload: false,
+            strip_ftl: false,
+            const_text: false,
+            cow_strings: false,
+            write_prefix: None,
+            concurrent: false,
+            langneg: cfg!(feature = "langneg"),
         }
     }
 }
@@ -61,6 +200,16 @@ impl BuildOptions {
         self
     }
 
+    /// Adds a folder with locales that are merged with the ones in the locales
+    /// folder. The resource prefix is prepended to the resource names of the folder.
+    pub fn with_additional_locales(mut self, folder: &str, resource_prefix: Option<&str>) -> Self {
+        self.additional_locales.push(AdditionalLocales {
+            folder: folder.to_string(),
+            resource_prefix: resource_prefix.map(str::to_string),
+        });
+        self
+    }
+
     pub fn with_output_file_path(mut self, output_file_path: &str) -> Self {
         self.output_file_path = output_file_path.to_string();
         self
@@ -91,10 +240,105 @@ impl BuildOptions {
         self
     }
 
+    pub fn with_message_ids(mut self) -> Self {
+        self.message_ids = true;
+        self
+    }
+
+    pub fn with_messages_trait(mut self) -> Self {
+        self.messages_trait = true;
+        self
+    }
+
+    pub fn with_args_struct_above(mut self, variable_count: usize) -> Self {
+        self.args_struct_above = Some(variable_count);
+        self
+    }
+
+    pub fn with_doc_translations(mut self, doc_translations: DocTranslations) -> Self {
+        self.doc_translations = doc_translations;
+        self
+    }
+
+    pub fn with_out_dir(mut self) -> Self {
+        self.out_dir = true;
+        self
+    }
+
+    pub fn with_missing_messages(mut self, policy: ValidationPolicy) -> Self {
+        self.missing_messages = policy;
+        self
+    }
+
+    pub fn with_signature_mismatches(mut self, policy: ValidationPolicy) -> Self {
+        self.signature_mismatches = policy;
+        self
+    }
+
+    pub fn with_hot_reload(mut self) -> Self {
+        self.hot_reload = true;
+        self
+    }
+
+    pub fn with_strip_ftl(mut self) -> Self {
+        self.strip_ftl = true;
+        self
+    }
+
+    pub fn with_const_text(mut self) -> Self {
+        self.const_text = true;
+        self
+    }
+
+    pub fn with_cow_strings(mut self) -> Self {
+        self.cow_strings = true;
+        self
+    }
+
+    pub fn with_concurrent(mut self) -> Self {
+        self.concurrent = true;
+        self
+    }
+
+    pub fn with_langneg(mut self) -> Self {
+        self.langneg = true;
+        self
+    }
+
+    pub fn without_langneg(mut self) -> Self {
+        self.langneg = false;
+        self
+    }
+
+    pub fn with_write_prefix(mut self, prefix: &str) -> Self {
+        self.write_prefix = Some(prefix.to_string());
+        self
+    }
+
     #[deprecated(note = "Use with_output_mode(OutputMode::String { prefix }) instead")]
     pub fn with_prefix(self, prefix: &str) -> Self {
         self.with_output_mode(OutputMode::String {
             prefix: prefix.to_string(),
         })
     }
+
+    /// Moves the output rust file into the given folder, together with the
+    /// single ftl file or the ftl files of the multi file output.
+    pub(crate) fn move_into(&mut self, folder: &str) {
+        self.output_file_path = path_in(folder, &self.output_file_path);
+        match &mut self.ftl_output {
+            FtlOutputOptions::SingleFile {
+                output_ftl_file, ..
+            } => *output_ftl_file = path_in(folder, output_ftl_file),
+            FtlOutputOptions::MultiFile {
+                output_ftl_folder, ..
+            } => *output_ftl_folder = folder.to_string(),
+            FtlOutputOptions::Inline => {}
+        }
+    }
+}
+
+fn path_in(folder: &str, file: &str) -> String {
+    let name = Path::new(file).file_name().unwrap_or(file.as_ref());
+    Path::new(folder).join(name).to_string_lossy().to_string()
 }
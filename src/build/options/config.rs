@@ -0,0 +1,275 @@
+use serde::Deserialize;
+use std::{fs, path::Path};
+
+use super::output_mode::{DEFAULT_PATTERN_PREFIX, DEFAULT_STRING_PREFIX};
+use super::{
+    AdditionalLocales, BuildOptions, Compressor, DocTranslations, FtlOutputOptions, OutputMode,
+    ValidationPolicy,
+};
+use crate::Codec;
+
+const CONFIG_FILE: &str = "fluent-typed.toml";
+
+/// The options that can be set in a `fluent-typed.toml` file or in the
+/// `[package.metadata.fluent-typed]` table of `Cargo.toml`. Each of them
+/// corresponds to a field of [BuildOptions].
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct Config {
+    locales_folder: Option<String>,
+    additional_locales: Option<Vec<AdditionalLocales>>,
+    output_file_path: Option<String>,
+    indentation: Option<String>,
+    default_language: Option<String>,
+    format: Option<bool>,
+    output_mode: Option<OutputModeConfig>,
+    string_prefix: Option<String>,
+    pattern_prefix: Option<String>,
+    write_prefix: Option<String>,
+    ftl_output: Option<FtlOutputConfig>,
+    ftl_output_path: Option<String>,
+    ftl_codec: Option<Codec>,
+    message_ids: Option<bool>,
+    messages_trait: Option<bool>,
+    args_struct_above: Option<usize>,
+    doc_translations: Option<DocTranslations>,
+    out_dir: Option<bool>,
+    hot_reload: Option<bool>,
+    strip_ftl: Option<bool>,
+    const_text: Option<bool>,
+    cow_strings: Option<bool>,
+    concurrent: Option<bool>,
+    langneg: Option<bool>,
+    missing_messages: Option<ValidationPolicy>,
+    signature_mismatches: Option<ValidationPolicy>,
+    /// The file the configuration was read from.
+    #[serde(skip)]
+    file: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum OutputModeConfig {
+    String,
+    Pattern,
+    Both,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum FtlOutputConfig {
+    SingleFile,
+    MultiFile,
+    EmbeddedMultiFile,
+    Inline,
+}
+
+impl BuildOptions {
+    /// Loads the options from the `fluent-typed.toml` file in the given
+    /// package folder or, when there is none, from the
+    /// `[package.metadata.fluent-typed]` table of its `Cargo.toml`. The
+    /// defaults are used for options that are not configured, and the
+    /// configured paths are relative to the package folder.
+    ///
+    /// Custom compressors can't be configured and have to be set in code.
+    pub fn from_package_config(package_folder: &str) -> Result<Self, String> {
+        let folder = Path::new(package_folder);
+        let config_file = folder.join(CONFIG_FILE);
+        if config_file.exists() {
+            return Self::from_config_file(&config_file.to_string_lossy());
+        }
+        let cargo_file = folder.join("Cargo.toml");
+        if !cargo_file.exists() {
+            return Ok(Self::default());
+        }
+        let content = read(&cargo_file)?;
+        let cargo: toml::Table = toml::from_str(&content)
+            .map_err(|e| format!("Could not parse '{}': {e}", cargo_file.display()))?;
+        let metadata = cargo
+            .get("package")
+            .and_then(|p| p.get("metadata"))
+            .and_then(|m| m.get("fluent-typed"));
+        let mut config: Config = match metadata {
+            Some(metadata) => metadata.clone().try_into().map_err(|e| {
+                format!(
+                    "Invalid [package.metadata.fluent-typed] in '{}': {e}",
+                    cargo_file.display()
+                )
+            })?,
+            None => Config::default(),
+        };
+        config.file = Some(cargo_file.to_string_lossy().to_string());
+        config.apply(Self::default())
+    }
+
+    /// Loads the options from a toml file, such as `fluent-typed.toml`. The
+    /// defaults are used for options that are not configured, and the
+    /// configured paths are relative to the folder of the file.
+    pub fn from_config_file(file: &str) -> Result<Self, String> {
+        let file = Path::new(file);
+        let mut config: Config = toml::from_str(&read(file)?)
+            .map_err(|e| format!("Invalid configuration in '{}': {e}", file.display()))?;
+        config.file = Some(file.to_string_lossy().to_string());
+        config.apply(Self::default())
+    }
+}
+
+impl Config {
+    fn apply(self, mut options: BuildOptions) -> Result<BuildOptions, String> {
+        options.output_mode = self.output_mode(options.output_mode)?;
+        options.ftl_output = self.ftl_output(options.ftl_output)?;
+        if let Some(folder) = &self.locales_folder {
+            options.locales_folder = self.resolve(folder);
+        }
+        if let Some(additional) = &self.additional_locales {
+            options.additional_locales = additional
+                .iter()
+                .map(|a| AdditionalLocales {
+                    folder: self.resolve(&a.folder),
+                    resource_prefix: a.resource_prefix.clone(),
+                })
+                .collect();
+        }
+        if let Some(path) = &self.output_file_path {
+            options.output_file_path = self.resolve(path);
+        }
+        if let Some(indentation) = self.indentation {
+            options.indentation = indentation;
+        }
+        if let Some(lang) = self.default_language {
+            options.default_language = lang;
+        }
+        if let Some(format) = self.format {
+            options.format = format;
+        }
+        if let Some(message_ids) = self.message_ids {
+            options.message_ids = message_ids;
+        }
+        if let Some(messages_trait) = self.messages_trait {
+            options.messages_trait = messages_trait;
+        }
+        if let Some(count) = self.args_struct_above {
+            options.args_struct_above = Some(count);
+        }
+        if let Some(doc_translations) = self.doc_translations {
+            options.doc_translations = doc_translations;
+        }
+        if let Some(out_dir) = self.out_dir {
+            options.out_dir = out_dir;
+        }
+        if let Some(hot_reload) = self.hot_reload {
+            options.hot_reload = hot_reload;
+        }
+        if let Some(strip_ftl) = self.strip_ftl {
+            options.strip_ftl = strip_ftl;
+        }
+        if let Some(const_text) = self.const_text {
+            options.const_text = const_text;
+        }
+        if let Some(prefix) = self.write_prefix {
+            options.write_prefix = Some(prefix);
+        }
+        if let Some(concurrent) = self.concurrent {
+            options.concurrent = concurrent;
+        }
+        if let Some(langneg) = self.langneg {
+            options.langneg = langneg;
+        }
+        if let Some(cow_strings) = self.cow_strings {
+            options.cow_strings = cow_strings;
+        }
+        if let Some(policy) = self.missing_messages {
+            options.missing_messages = policy;
+        }
+        if let Some(policy) = self.signature_mismatches {
+            options.signature_mismatches = policy;
+        }
+        options.config_file = self.file;
+        Ok(options)
+    }
+
+    fn output_mode(&self, current: OutputMode) -> Result<OutputMode, String> {
+        let string_prefix = || {
+            self.string_prefix
+                .clone()
+                .unwrap_or_else(|| DEFAULT_STRING_PREFIX.to_string())
+        };
+        let pattern_prefix = || {
+            self.pattern_prefix
+                .clone()
+                .unwrap_or_else(|| DEFAULT_PATTERN_PREFIX.to_string())
+        };
+        Ok(match self.output_mode {
+            None if self.string_prefix.is_some() || self.pattern_prefix.is_some() => {
+                return Err(
+                    "The string-prefix and pattern-prefix require the output-mode".to_string(),
+                );
+            }
+            None => current,
+            Some(OutputModeConfig::String) => OutputMode::String {
+                prefix: string_prefix(),
+            },
+            Some(OutputModeConfig::Pattern) => OutputMode::Pattern {
+                prefix: pattern_prefix(),
+            },
+            Some(OutputModeConfig::Both) => OutputMode::Both {
+                string_prefix: string_prefix(),
+                pattern_prefix: pattern_prefix(),
+            },
+        })
+    }
+
+    /// Resolves a relative path of the configuration against the folder of
+    /// the configuration file.
+    fn resolve(&self, path: &str) -> String {
+        let folder = self
+            .file
+            .as_deref()
+            .and_then(|file| Path::new(file).parent())
+            .filter(|folder| !folder.as_os_str().is_empty() && *folder != Path::new("."));
+        match folder {
+            Some(folder) if Path::new(path).is_relative() => {
+                folder.join(path).to_string_lossy().to_string()
+            }
+            _ => path.to_string(),
+        }
+    }
+
+    fn ftl_output(&self, current: FtlOutputOptions) -> Result<FtlOutputOptions, String> {
+        let path = self
+            .ftl_output_path
+            .as_deref()
+            .map(|path| self.resolve(path));
+        let path = path.as_deref();
+        let mut ftl_output = match (self.ftl_output, path) {
+            (None, None) => current,
+            (None | Some(FtlOutputConfig::SingleFile), Some(file)) => {
+                FtlOutputOptions::single_file(file)
+            }
+            (Some(FtlOutputConfig::SingleFile), None) => FtlOutputOptions::default(),
+            (Some(FtlOutputConfig::MultiFile), folder) => {
+                FtlOutputOptions::multi_file(folder.unwrap_or("gen/"))
+            }
+            (Some(FtlOutputConfig::EmbeddedMultiFile), folder) => {
+                FtlOutputOptions::multi_file_embedded(folder.unwrap_or("gen/"))
+            }
+            (Some(FtlOutputConfig::Inline), None) => FtlOutputOptions::inline(),
+            (Some(FtlOutputConfig::Inline), Some(_)) => {
+                return Err("The ftl-output-path can't be used with inline ftl output".to_string());
+            }
+        };
+        if let Some(codec) = self.ftl_codec {
+            let FtlOutputOptions::SingleFile { compressor, .. } = &mut ftl_output else {
+                return Err(
+                    "The ftl-codec can only be used with single file ftl output".to_string()
+                );
+            };
+            *compressor = Some(Compressor::Codec(codec));
+        }
+        Ok(ftl_output)
+    }
+}
+
+fn read(file: &Path) -> Result<String, String> {
+    fs::read_to_string(file).map_err(|e| format!("Could not read '{}': {e}", file.display()))
+}
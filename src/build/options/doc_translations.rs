@@ -0,0 +1,18 @@
+/// Controls which translations are added to the documentation of the
+/// generated message functions, in addition to the comment of the message
+/// in the ftl file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "config",
+    derive(serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum DocTranslations {
+    /// Only the comment of the message is added.
+    None,
+    /// The translation in the default language is added.
+    #[default]
+    DefaultLanguage,
+    /// A table with the translations in all languages is added.
+    AllLanguages,
+}
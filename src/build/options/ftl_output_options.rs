@@ -1,12 +1,18 @@
 use std::{
     error::Error,
     fs,
+    ops::Range,
     path::{Path, PathBuf},
 };
 
-use crate::build::{LangBundle, r#gen::GeneratedFtl};
+use crate::{
+    Codec,
+    build::r#gen::{Compression, GeneratedFtl},
+};
 
 type CompressorFn = dyn Fn(Vec<u8>) -> Result<Vec<u8>, Box<dyn Error>>;
+/// The ftl content and the byte range of each language in it.
+type Concatenated = (Vec<u8>, Vec<(String, Range<usize>)>);
 
 /// The ftl output options for the build command. This allows you to
 /// configure how the output ftl files are generated, and also what
@@ -28,6 +34,12 @@ pub enum FtlOutputOptions {
         ///
         /// Defaults to "gen/" in the root of the package.
         output_ftl_folder: String,
+        /// Embeds each ftl file in the binary as a static of its own. The
+        /// language is then parsed on first access with the generated
+        /// `L10n::get()`, so that unused languages are never parsed.
+        ///
+        /// The generated `L10nLanguage` is then `Send + Sync`.
+        embed: bool,
     },
 
     /// Generates FTL files as one file for all languages, which means
@@ -44,14 +56,32 @@ pub enum FtlOutputOptions {
         ///
         /// Defaults to "gen/translations.ftl" in the root of the package.
         output_ftl_file: String,
-        /// The compresssor is an closure that takes the ftl file content
-        /// as a byte array, compresses it and returns the compressed bytes.
-        ///
-        /// Any compression algorithm can be used, but it's up to the user
-        /// to import the necessary crate and do the compression and when
-        /// using it decompress in the same manner.
-        compressor: Option<Box<CompressorFn>>,
+        /// The compressor of the ftl content. Each language is compressed on
+        /// its own, so that loading a language only decompresses its own part
+        /// of the file.
+        compressor: Option<Compressor>,
     },
+
+    /// Embeds the ftl of all languages directly in the generated rust
+    /// file, so that no ftl file is written.
+    ///
+    /// This is used by the `l10n!` macro, which generates the code at
+    /// compile time.
+    Inline,
+}
+
+/// How the ftl of each language is compressed in the single ftl file.
+pub enum Compressor {
+    /// A closure that takes the ftl content of one language as a byte array,
+    /// compresses it and returns the compressed bytes.
+    ///
+    /// Any compression algorithm can be used, but it's up to the user
+    /// to import the necessary crate and do the compression and when
+    /// using it decompress in the same manner.
+    Custom(Box<CompressorFn>),
+    /// A built-in codec, which the generated `load` functions decompress
+    /// automatically. It requires the cargo feature of the codec.
+    Codec(Codec),
 }
 
 impl Default for FtlOutputOptions {
@@ -77,27 +107,56 @@ impl FtlOutputOptions {
     {
         Self::SingleFile {
             output_ftl_file: file.to_string(),
-            compressor: Some(Box::new(compressor)),
+            compressor: Some(Compressor::Custom(Box::new(compressor))),
+        }
+    }
+
+    pub fn single_file_with_codec(file: &str, codec: Codec) -> Self {
+        Self::SingleFile {
+            output_ftl_file: file.to_string(),
+            compressor: Some(Compressor::Codec(codec)),
         }
     }
 
     pub fn multi_file(folder: &str) -> Self {
         Self::MultiFile {
             output_ftl_folder: folder.to_string(),
+            embed: false,
+        }
+    }
+
+    pub fn multi_file_embedded(folder: &str) -> Self {
+        Self::MultiFile {
+            output_ftl_folder: folder.to_string(),
+            embed: true,
         }
     }
 
-    pub fn generate(&self, locales: &[LangBundle]) -> Result<GeneratedFtl, String> {
+    pub fn inline() -> Self {
+        Self::Inline
+    }
+
+    /// Writes the ftl of the languages, given as pairs of language id and
+    /// ftl, and returns what is needed to generate the access code.
+    pub fn generate(&self, ftls: &[(String, String)]) -> Result<GeneratedFtl, String> {
         match self {
-            Self::MultiFile { output_ftl_folder } => {
+            Self::MultiFile {
+                output_ftl_folder,
+                embed,
+            } => {
                 let dir = PathBuf::from(output_ftl_folder);
                 create_dir(&dir)?;
-                for lang in locales {
-                    let mut file = dir.join(&lang.language_id);
-                    file.set_extension("ftl");
-                    write(lang.ftl.as_bytes(), &file)?;
+                let mut files = Vec::new();
+                for (lang, ftl) in ftls {
+                    let name = format!("{lang}.ftl");
+                    write(ftl.as_bytes(), &dir.join(&name))?;
+                    files.push((lang.clone(), name));
                 }
-                Ok(GeneratedFtl::MultiFile)
+                Ok(GeneratedFtl::MultiFile {
+                    output_ftl_folder: output_ftl_folder.clone(),
+                    files,
+                    embed: *embed,
+                })
             }
             Self::SingleFile {
                 output_ftl_file,
@@ -107,32 +166,67 @@ impl FtlOutputOptions {
                 if let Some(folder) = file.parent() {
                     create_dir(folder)?;
                 }
-                let mut content = Vec::new();
-                let mut pos = 0;
-                let mut positions = Vec::new();
-
-                for locale in locales {
-                    let bytes = locale.ftl.bytes();
-                    content.extend(bytes);
-                    let lang = locale.language_id.clone();
-                    positions.push((lang, pos..content.len()));
-                    pos = content.len();
-                }
-                if let Some(compressor) = compressor {
-                    let compressed = compressor(content)
-                        .map_err(|e| format!("Could not compress ftl file: {e}"))?;
-                    write(&compressed, &file)?;
-                } else {
-                    write(&content, &file)?;
-                }
+                let (content, positions) = match compressor {
+                    Some(compressor) => concat_compressed(ftls, compressor)?,
+                    None => concat(ftls),
+                };
+                write(&content, &file)?;
                 Ok(GeneratedFtl::SingleFile {
                     output_ftl_file: output_ftl_file.clone(),
                     positions,
-                    compressed: compressor.is_some(),
+                    compression: match compressor {
+                        None => Compression::None,
+                        Some(Compressor::Custom(_)) => Compression::Custom,
+                        Some(Compressor::Codec(codec)) => Compression::Codec(*codec),
+                    },
                 })
             }
+            Self::Inline => {
+                let (content, positions) = concat(ftls);
+                let content = String::from_utf8(content)
+                    .map_err(|e| format!("Could not inline ftl content: {e}"))?;
+                Ok(GeneratedFtl::Inline { content, positions })
+            }
+        }
+    }
+}
+
+/// Joins the ftl of all languages, and returns the byte range of each language.
+fn concat(ftls: &[(String, String)]) -> Concatenated {
+    let mut content = Vec::new();
+    let mut pos = 0;
+    let mut positions = Vec::new();
+
+    for (lang, ftl) in ftls {
+        content.extend(ftl.bytes());
+        positions.push((lang.clone(), pos..content.len()));
+        pos = content.len();
+    }
+    (content, positions)
+}
+
+/// Compresses the ftl of each language on its own and joins them, and returns the
+/// byte range of each language in the compressed content.
+fn concat_compressed(
+    ftls: &[(String, String)],
+    compressor: &Compressor,
+) -> Result<Concatenated, String> {
+    let mut content = Vec::new();
+    let mut positions = Vec::new();
+
+    for (lang, ftl) in ftls {
+        let compressed = match compressor {
+            Compressor::Custom(compressor) => {
+                compressor(ftl.as_bytes().to_vec()).map_err(|e| e.to_string())
+            }
+            Compressor::Codec(codec) => codec.compress(ftl.as_bytes()),
         }
+        .map_err(|e| format!("Could not compress ftl of language '{lang}': {e}"))?;
+        let start = content.len();
+        content.extend(compressed);
+        positions.push((lang.clone(), start..content.len()));
     }
+    Ok((content, positions))
 }
 
 fn write(content: &[u8], file: &Path) -> Result<(), String> {
@@ -141,7 +235,7 @@ fn write(content: &[u8], file: &Path) -> Result<(), String> {
 
 fn create_dir(folder: &Path) -> Result<(), String> {
     if !folder.exists() {
-        fs::create_dir_all(&folder)
+        fs::create_dir_all(folder)
             .map_err(|e| format!("Could not create ftl folder '{folder:?}': {e:?}"))?;
     }
     Ok(())
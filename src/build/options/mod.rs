@@ -1,7 +1,15 @@
+mod additional_locales;
 mod build_options;
+#[cfg(feature = "config")]
+mod config;
+mod doc_translations;
 mod ftl_output_options;
 mod output_mode;
+mod validation_policy;
 
+pub use additional_locales::AdditionalLocales;
 pub use build_options::BuildOptions;
-pub use ftl_output_options::FtlOutputOptions;
+pub use doc_translations::DocTranslations;
+pub use ftl_output_options::{Compressor, FtlOutputOptions};
 pub use output_mode::OutputMode;
+pub use validation_policy::ValidationPolicy;
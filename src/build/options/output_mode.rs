@@ -1,5 +1,5 @@
-const DEFAULT_STRING_PREFIX: &str = "msg_";
-const DEFAULT_PATTERN_PREFIX: &str = "ptn_";
+pub(super) const DEFAULT_STRING_PREFIX: &str = "msg_";
+pub(super) const DEFAULT_PATTERN_PREFIX: &str = "ptn_";
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OutputMode {
@@ -0,0 +1,16 @@
+/// How a validation problem found in the locales is reported during the build.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "config",
+    derive(serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum ValidationPolicy {
+    /// The problem is printed as a cargo warning.
+    #[default]
+    Warn,
+    /// The build fails.
+    Error,
+    /// The problem is not reported.
+    Ignore,
+}
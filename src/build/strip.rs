@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+
+use fluent_syntax::ast::{
+    CallArguments, Entry, Expression, InlineExpression, Pattern, PatternElement, Resource,
+};
+use fluent_syntax::{parser, serializer};
+
+use super::Message;
+
+/// Re-serializes the ftl without comments, keeping only the given messages and
+/// the messages and terms that they reference, directly or indirectly.
+pub fn strip_ftl(ftl: &str, messages: &[&Message]) -> Result<String, String> {
+    let ast = parser::parse(ftl).map_err(|(_, e)| format!("Could not parse ftl due to: {e:?}"))?;
+
+    let mut keep = messages
+        .iter()
+        .map(|msg| Ref::Message(msg.id.message.as_str()))
+        .collect::<HashSet<_>>();
+    let mut pending = keep.iter().copied().collect::<Vec<_>>();
+    while let Some(entry) = pending.pop() {
+        for pattern in patterns(&ast, entry) {
+            let mut refs = Vec::new();
+            pattern_refs(pattern, &mut refs);
+            pending.extend(refs.into_iter().filter(|r| keep.insert(*r)));
+        }
+    }
+
+    let body = ast
+        .body
+        .iter()
+        .filter_map(|entry| match entry {
+            Entry::Message(m) if keep.contains(&Ref::Message(m.id.name)) => {
+                let mut m = m.clone();
+                m.comment = None;
+                Some(Entry::Message(m))
+            }
+            Entry::Term(t) if keep.contains(&Ref::Term(t.id.name)) => {
+                let mut t = t.clone();
+                t.comment = None;
+                Some(Entry::Term(t))
+            }
+            _ => None,
+        })
+        .collect();
+    Ok(serializer::serialize(&Resource { body }))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Ref<'s> {
+    Message(&'s str),
+    Term(&'s str),
+}
+
+/// The value and attribute patterns of the message or term.
+fn patterns<'a, 's>(ast: &'a Resource<&'s str>, entry: Ref<'s>) -> Vec<&'a Pattern<&'s str>> {
+    ast.body
+        .iter()
+        .filter_map(|e| match (e, entry) {
+            (Entry::Message(m), Ref::Message(id)) if m.id.name == id => Some(
+                m.value
+                    .iter()
+                    .chain(m.attributes.iter().map(|a| &a.value))
+                    .collect::<Vec<_>>(),
+            ),
+            (Entry::Term(t), Ref::Term(id)) if t.id.name == id => Some(
+                std::iter::once(&t.value)
+                    .chain(t.attributes.iter().map(|a| &a.value))
+                    .collect(),
+            ),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+fn pattern_refs<'s>(pattern: &Pattern<&'s str>, refs: &mut Vec<Ref<'s>>) {
+    for element in &pattern.elements {
+        if let PatternElement::Placeable { expression } = element {
+            expression_refs(expression, refs);
+        }
+    }
+}
+
+fn expression_refs<'s>(expression: &Expression<&'s str>, refs: &mut Vec<Ref<'s>>) {
+    match expression {
+        Expression::Select { selector, variants } => {
+            inline_refs(selector, refs);
+            for variant in variants {
+                pattern_refs(&variant.value, refs);
+            }
+        }
+        Expression::Inline(inline) => inline_refs(inline, refs),
+    }
+}
+
+fn inline_refs<'s>(inline: &InlineExpression<&'s str>, refs: &mut Vec<Ref<'s>>) {
+    match inline {
+        InlineExpression::MessageReference { id, .. } => refs.push(Ref::Message(id.name)),
+        InlineExpression::TermReference { id, arguments, .. } => {
+            refs.push(Ref::Term(id.name));
+            if let Some(arguments) = arguments {
+                argument_refs(arguments, refs);
+            }
+        }
+        InlineExpression::FunctionReference { arguments, .. } => argument_refs(arguments, refs),
+        InlineExpression::Placeable { expression } => expression_refs(expression, refs),
+        InlineExpression::StringLiteral { .. }
+        | InlineExpression::NumberLiteral { .. }
+        | InlineExpression::VariableReference { .. } => {}
+    }
+}
+
+fn argument_refs<'s>(arguments: &CallArguments<&'s str>, refs: &mut Vec<Ref<'s>>) {
+    for arg in &arguments.positional {
+        inline_refs(arg, refs);
+    }
+    for arg in &arguments.named {
+        inline_refs(&arg.value, refs);
+    }
+}
@@ -1,4 +1,5 @@
 mod parse_ast;
+mod render;
 mod type_in_comment;
 
 use std::fmt::Display;
@@ -13,6 +14,12 @@ pub struct Message {
     pub resource: String,
     pub comment: Vec<String>,
     pub variables: Vec<Variable>,
+    /// The value of the message (or attribute) in a readable form.
+    /// This is used for the generated documentation.
+    pub value: String,
+    /// The value of the message (or attribute) when it is plain text
+    /// without any placeables.
+    pub text: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
@@ -62,6 +69,8 @@ impl Id {
 pub struct Attribute {
     pub id: String,
     pub variables: Vec<Variable>,
+    pub value: String,
+    pub text: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -1,5 +1,6 @@
 use super::*;
 use fluent_syntax::ast;
+use render::{plain_text, render_pattern};
 use type_in_comment::TypeInComment;
 
 impl Message {
@@ -23,6 +24,8 @@ impl Message {
                 id,
                 comment,
                 variables,
+                value: render_pattern(value),
+                text: plain_text(value),
             });
         }
         for attribute in find_attributes(&message.attributes) {
@@ -36,6 +39,8 @@ impl Message {
                 id,
                 comment: vec![],
                 variables,
+                value: attribute.value,
+                text: attribute.text,
             });
         }
         found
@@ -48,6 +53,8 @@ impl Attribute {
         Self {
             id: attribute.id.name.to_owned(),
             variables,
+            value: render_pattern(&attribute.value),
+            text: plain_text(&attribute.value),
         }
     }
 }
@@ -0,0 +1,96 @@
+use fluent_syntax::ast::{
+    CallArguments, Expression, InlineExpression, Pattern, PatternElement, Variant, VariantKey,
+};
+
+/// Renders a pattern in a readable, FTL-like form, with variables,
+/// references and selectors kept as placeables.
+pub fn render_pattern(pattern: &Pattern<&str>) -> String {
+    pattern
+        .elements
+        .iter()
+        .map(|element| match element {
+            PatternElement::TextElement { value } => value.to_string(),
+            PatternElement::Placeable { expression } => {
+                format!("{{ {} }}", render_expression(expression))
+            }
+        })
+        .collect()
+}
+
+/// The text of a pattern that has no placeables, which formats to
+/// the same text in every bundle.
+pub fn plain_text(pattern: &Pattern<&str>) -> Option<String> {
+    pattern
+        .elements
+        .iter()
+        .map(|element| match element {
+            PatternElement::TextElement { value } => Some(*value),
+            PatternElement::Placeable { .. } => None,
+        })
+        .collect()
+}
+
+fn render_expression(expression: &Expression<&str>) -> String {
+    match expression {
+        Expression::Inline(inline) => render_inline(inline),
+        Expression::Select { selector, variants } => {
+            let variants = variants
+                .iter()
+                .map(render_variant)
+                .collect::<Vec<_>>()
+                .join(" ");
+            format!("{} -> {variants}", render_inline(selector))
+        }
+    }
+}
+
+fn render_variant(variant: &Variant<&str>) -> String {
+    let key = match variant.key {
+        VariantKey::Identifier { name } => name,
+        VariantKey::NumberLiteral { value } => value,
+    };
+    let default = if variant.default { "*" } else { "" };
+    format!("{default}[{key}] {}", render_pattern(&variant.value))
+}
+
+fn render_inline(inline: &InlineExpression<&str>) -> String {
+    match inline {
+        InlineExpression::StringLiteral { value } => format!("\"{value}\""),
+        InlineExpression::NumberLiteral { value } => value.to_string(),
+        InlineExpression::FunctionReference { id, arguments } => {
+            format!("{}({})", id.name, render_arguments(arguments))
+        }
+        InlineExpression::MessageReference { id, attribute } => match attribute {
+            Some(attr) => format!("{}.{}", id.name, attr.name),
+            None => id.name.to_string(),
+        },
+        InlineExpression::TermReference {
+            id,
+            attribute,
+            arguments,
+        } => {
+            let attr = attribute
+                .as_ref()
+                .map(|a| format!(".{}", a.name))
+                .unwrap_or_default();
+            let args = arguments
+                .as_ref()
+                .map(|a| format!("({})", render_arguments(a)))
+                .unwrap_or_default();
+            format!("-{}{attr}{args}", id.name)
+        }
+        InlineExpression::VariableReference { id } => format!("${}", id.name),
+        InlineExpression::Placeable { expression } => {
+            format!("{{ {} }}", render_expression(expression))
+        }
+    }
+}
+
+fn render_arguments(arguments: &CallArguments<&str>) -> String {
+    let positional = arguments.positional.iter().map(render_inline);
+    let named = arguments
+        .named
+        .iter()
+        .map(|arg| format!("{}: {}", arg.name.name, render_inline(&arg.value)));
+    positional.chain(named).collect::<Vec<_>>().join(", ")
+}
@@ -6,6 +6,8 @@ pub trait Traversable {
         &self,
         condition: impl Fn(&Path) -> bool,
     ) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>>;
+
+    fn gather_all_dirs(&self) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>>;
 }
 
 impl Traversable for Path {
@@ -23,6 +25,29 @@ impl Traversable for Path {
 
         Ok(paths)
     }
+
+    fn gather_all_dirs(&self) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+        let mut dirs = Vec::new();
+        if self.is_dir() {
+            dirs.push(self.to_path_buf());
+            gather_dirs_recursive(self, &mut dirs)?;
+        }
+        Ok(dirs)
+    }
+}
+
+fn gather_dirs_recursive(
+    dir: &Path,
+    dirs: &mut Vec<PathBuf>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            dirs.push(path.clone());
+            gather_dirs_recursive(&path, dirs)?;
+        }
+    }
+    Ok(())
 }
 
 fn gather_paths_recursive(
@@ -0,0 +1,149 @@
+#[cfg(any(feature = "gzip", feature = "brotli"))]
+use std::io::Read;
+#[cfg(feature = "gzip")]
+use std::io::Write;
+
+/// The built-in compression codecs for the single ftl file, which are
+/// each behind the cargo feature with the same name.
+///
+/// The compressed data of each language starts with a header byte that
+/// identifies the codec, so that it is decompressed with the matching one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "config",
+    derive(serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum Codec {
+    Gzip,
+    Zstd,
+    Brotli,
+}
+
+impl Codec {
+    /// The header byte of the compressed data.
+    pub fn header(self) -> u8 {
+        match self {
+            Self::Gzip => 1,
+            Self::Zstd => 2,
+            Self::Brotli => 3,
+        }
+    }
+
+    /// The codec of the header byte.
+    pub fn from_header(header: u8) -> Result<Self, String> {
+        match header {
+            1 => Ok(Self::Gzip),
+            2 => Ok(Self::Zstd),
+            3 => Ok(Self::Brotli),
+            _ => Err(format!("Unknown compression header: {header}")),
+        }
+    }
+
+    /// Whether the cargo feature of the codec is enabled, which is checked
+    /// at compile time by the generated code that uses the codec.
+    pub const fn is_enabled(self) -> bool {
+        match self {
+            Self::Gzip => cfg!(feature = "gzip"),
+            Self::Zstd => cfg!(feature = "zstd"),
+            Self::Brotli => cfg!(feature = "brotli"),
+        }
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Gzip => "gzip",
+            Self::Zstd => "zstd",
+            Self::Brotli => "brotli",
+        }
+    }
+
+    /// Compresses the bytes, and prepends the header byte.
+    #[cfg_attr(
+        not(any(feature = "gzip", feature = "zstd", feature = "brotli")),
+        allow(unused_variables)
+    )]
+    pub fn compress(self, bytes: &[u8]) -> Result<Vec<u8>, String> {
+        #[cfg_attr(
+            not(any(feature = "gzip", feature = "zstd", feature = "brotli")),
+            allow(unused_mut)
+        )]
+        let mut out = vec![self.header()];
+        let result: Result<(), String> = match self {
+            #[cfg(feature = "gzip")]
+            Self::Gzip => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(&mut out, flate2::Compression::best());
+                encoder
+                    .write_all(bytes)
+                    .and_then(|_| encoder.finish().map(|_| ()))
+                    .map_err(|e| self.error(e))
+            }
+            #[cfg(feature = "zstd")]
+            Self::Zstd => zstd::encode_all(bytes, 19)
+                .map(|compressed| out.extend(compressed))
+                .map_err(|e| self.error(e)),
+            #[cfg(feature = "brotli")]
+            Self::Brotli => {
+                let params = brotli::enc::BrotliEncoderParams {
+                    quality: 11,
+                    lgwin: 22,
+                    ..Default::default()
+                };
+                brotli::BrotliCompress(&mut &bytes[..], &mut out, &params)
+                    .map(|_| ())
+                    .map_err(|e| self.error(e))
+            }
+            #[allow(unreachable_patterns)]
+            _ => Err(self.missing_feature()),
+        };
+        result.map(|_| out)
+    }
+
+    /// Decompresses data that was compressed with [Codec::compress] by
+    /// any of the codecs.
+    #[cfg_attr(
+        not(any(feature = "gzip", feature = "zstd", feature = "brotli")),
+        allow(unused_variables)
+    )]
+    pub fn decompress(data: &[u8]) -> Result<Vec<u8>, String> {
+        let (header, data) = data
+            .split_first()
+            .ok_or_else(|| "The compressed data is empty".to_string())?;
+        let codec = Self::from_header(*header)?;
+        #[cfg_attr(
+            not(any(feature = "gzip", feature = "zstd", feature = "brotli")),
+            allow(unused_mut)
+        )]
+        let mut out = Vec::new();
+        let result: Result<(), String> = match codec {
+            #[cfg(feature = "gzip")]
+            Self::Gzip => flate2::read::GzDecoder::new(data)
+                .read_to_end(&mut out)
+                .map(|_| ())
+                .map_err(|e| codec.error(e)),
+            #[cfg(feature = "zstd")]
+            Self::Zstd => zstd::stream::copy_decode(data, &mut out).map_err(|e| codec.error(e)),
+            #[cfg(feature = "brotli")]
+            Self::Brotli => brotli::Decompressor::new(data, 4096)
+                .read_to_end(&mut out)
+                .map(|_| ())
+                .map_err(|e| codec.error(e)),
+            #[allow(unreachable_patterns)]
+            _ => Err(codec.missing_feature()),
+        };
+        result.map(|_| out)
+    }
+
+    #[cfg(any(feature = "gzip", feature = "zstd", feature = "brotli"))]
+    fn error(self, e: std::io::Error) -> String {
+        format!("The {} codec failed: {e}", self.name())
+    }
+
+    pub(crate) fn missing_feature(self) -> String {
+        format!(
+            "The {0} codec requires the '{0}' feature of fluent-typed",
+            self.name()
+        )
+    }
+}
@@ -0,0 +1,246 @@
+use std::{
+    borrow::Cow,
+    fmt, fs,
+    path::{Path, PathBuf},
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use fluent_bundle::FluentArgs;
+use fluent_syntax::ast::Pattern;
+use intl_memoizer::{IntlLangMemoizer, concurrent};
+
+use crate::prelude::{L10nBundle, Memoizer, MessageSignature};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A [L10nBundle] that reads the ftl files of its language from the locales
+/// folders on disk and reloads them when they change, which lets wording
+/// changes show up without rebuilding during development.
+///
+/// A reload only replaces the messages when the loaded ftl has the messages
+/// and variables that the code was generated for. Otherwise the problems are
+/// printed and the previous messages are kept.
+pub struct HotReloadBundle<M = IntlLangMemoizer> {
+    lang: String,
+    folders: Vec<PathBuf>,
+    signatures: &'static [MessageSignature],
+    bundle: RwLock<Arc<L10nBundle<M>>>,
+    changed: Arc<AtomicBool>,
+}
+
+/// A [HotReloadBundle] that is `Send + Sync` and can be shared between threads.
+pub type ConcurrentHotReloadBundle = HotReloadBundle<concurrent::IntlLangMemoizer>;
+
+impl<M: Memoizer> HotReloadBundle<M> {
+    /// Loads the ftl files of the language from the `<folder>/<lang>/`
+    /// folders, and watches them for changes. Relative folders are resolved
+    /// against the base folder, which is the package folder in the generated
+    /// code. The bytes are used when none of the folders exist, for instance
+    /// when the binary runs on another machine than the one it was built on.
+    pub fn new(
+        lang: impl AsRef<str>,
+        bytes: &[u8],
+        base: &str,
+        folders: &[&str],
+        signatures: &'static [MessageSignature],
+    ) -> Result<Self, String> {
+        let lang = lang.as_ref().to_string();
+        let folders = folders
+            .iter()
+            .map(|folder| Path::new(base).join(folder).join(&lang))
+            .filter(|folder| folder.is_dir())
+            .collect::<Vec<_>>();
+
+        // taken before reading, so that changes made while loading are picked up
+        let stamps = fingerprint(&folders);
+        let bundle = if folders.is_empty() {
+            L10nBundle::new(&lang, bytes)?
+        } else {
+            let bundle = L10nBundle::new(&lang, &read_ftl(&folders)?)?;
+            let errors = bundle.verify(signatures);
+            if !errors.is_empty() {
+                return Err(errors.join("\n"));
+            }
+            bundle
+        };
+
+        let changed = Arc::new(AtomicBool::new(false));
+        if !folders.is_empty() {
+            watch(folders.clone(), stamps, Arc::clone(&changed));
+        }
+
+        Ok(Self {
+            lang,
+            folders,
+            signatures,
+            bundle: RwLock::new(Arc::new(bundle)),
+            changed,
+        })
+    }
+
+    pub fn lang(&self) -> &str {
+        &self.lang
+    }
+
+    pub fn msg(&self, id: &str, args: Option<FluentArgs>) -> Result<String, String> {
+        self.reload_if_changed();
+        self.current().msg(id, args)
+    }
+
+    pub fn attr(&self, msg: &str, attr: &str, args: Option<FluentArgs>) -> Result<String, String> {
+        self.reload_if_changed();
+        self.current().attr(msg, attr, args)
+    }
+
+    /// Same as [HotReloadBundle::msg], as the messages can't be borrowed
+    /// from a bundle that is replaced on changes.
+    pub fn msg_cow(&self, id: &str, args: Option<FluentArgs>) -> Result<Cow<'_, str>, String> {
+        self.msg(id, args).map(Cow::Owned)
+    }
+
+    /// Same as [HotReloadBundle::attr], see [HotReloadBundle::msg_cow].
+    pub fn attr_cow(
+        &self,
+        msg: &str,
+        attr: &str,
+        args: Option<FluentArgs>,
+    ) -> Result<Cow<'_, str>, String> {
+        self.attr(msg, attr, args).map(Cow::Owned)
+    }
+
+    pub fn write_msg<W: fmt::Write>(
+        &self,
+        w: &mut W,
+        id: &str,
+        args: Option<FluentArgs>,
+    ) -> fmt::Result {
+        self.reload_if_changed();
+        self.current().write_msg(w, id, args)
+    }
+
+    pub fn write_attr<W: fmt::Write>(
+        &self,
+        w: &mut W,
+        msg: &str,
+        attr: &str,
+        args: Option<FluentArgs>,
+    ) -> fmt::Result {
+        self.reload_if_changed();
+        self.current().write_attr(w, msg, attr, args)
+    }
+
+    pub fn msg_pattern(&self, id: &str) -> Pattern<String> {
+        self.reload_if_changed();
+        self.current().msg_pattern(id)
+    }
+
+    pub fn attr_pattern(&self, msg: &str, attr: &str) -> Pattern<String> {
+        self.reload_if_changed();
+        self.current().attr_pattern(msg, attr)
+    }
+
+    /// Checks the currently loaded messages, see [L10nBundle::verify].
+    pub fn verify(&self, signatures: &[MessageSignature]) -> Vec<String> {
+        self.current().verify(signatures)
+    }
+
+    /// Reloads the ftl files now, instead of waiting for the watcher to
+    /// notice a change. Fails, and keeps the previous messages, when the ftl
+    /// doesn't match the generated code.
+    pub fn reload(&self) -> Result<(), String> {
+        let bundle = L10nBundle::new(&self.lang, &read_ftl(&self.folders)?)?;
+        let errors = bundle.verify(self.signatures);
+        if !errors.is_empty() {
+            return Err(errors.join("\n"));
+        }
+        *self.bundle.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(bundle);
+        Ok(())
+    }
+
+    /// The loaded bundle, which is kept alive while in use, even when it is
+    /// replaced by a reload in the meantime.
+    fn current(&self) -> Arc<L10nBundle<M>> {
+        Arc::clone(&self.bundle.read().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    fn reload_if_changed(&self) {
+        if !self.changed.swap(false, Ordering::Relaxed) {
+            return;
+        }
+        if let Err(e) = self.reload() {
+            eprintln!(
+                "fluent-typed: keeping the previous '{}' messages: {e}",
+                self.lang
+            );
+        }
+    }
+}
+
+/// Polls the modification times of the ftl files in a background thread,
+/// which stops when the bundle is dropped.
+fn watch(folders: Vec<PathBuf>, mut last: Fingerprint, changed: Arc<AtomicBool>) {
+    thread::spawn(move || {
+        while Arc::strong_count(&changed) > 1 {
+            thread::sleep(POLL_INTERVAL);
+            let current = fingerprint(&folders);
+            if current != last {
+                changed.store(true, Ordering::Relaxed);
+                last = current;
+            }
+        }
+    });
+}
+
+type Fingerprint = Vec<(PathBuf, Option<(SystemTime, u64)>)>;
+
+fn fingerprint(folders: &[PathBuf]) -> Fingerprint {
+    ftl_files(folders)
+        .into_iter()
+        .map(|file| {
+            let meta = fs::metadata(&file).ok();
+            let stamp = meta.and_then(|m| Some((m.modified().ok()?, m.len())));
+            (file, stamp)
+        })
+        .collect()
+}
+
+/// Joins the ftl files of the folders, in the same order as when generating.
+fn read_ftl(folders: &[PathBuf]) -> Result<Vec<u8>, String> {
+    let mut ftl = Vec::new();
+    for file in ftl_files(folders) {
+        let content =
+            fs::read(&file).map_err(|e| format!("Could not read '{}': {e}", file.display()))?;
+        ftl.extend(content);
+        ftl.push(b'\n');
+    }
+    Ok(ftl)
+}
+
+fn ftl_files(folders: &[PathBuf]) -> Vec<PathBuf> {
+    let mut all = Vec::new();
+    for folder in folders {
+        let mut files = Vec::new();
+        gather(folder, &mut files);
+        files.sort();
+        all.extend(files);
+    }
+    all
+}
+
+fn gather(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.flatten().map(|e| e.path()) {
+        if path.is_dir() {
+            gather(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "ftl") {
+            files.push(path);
+        }
+    }
+}
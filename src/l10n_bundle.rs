@@ -1,21 +1,45 @@
-use fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+use fluent_bundle::{FluentArgs, FluentResource, bundle::FluentBundle, memoizer::MemoizerKind};
 use fluent_syntax::ast::{
     CallArguments, Expression, Identifier, InlineExpression, NamedArgument, Pattern,
     PatternElement, Variant, VariantKey,
 };
+use intl_memoizer::{IntlLangMemoizer, concurrent};
+use std::{borrow::Cow, fmt};
 use unic_langid::LanguageIdentifier;
 
-pub struct L10nBundle {
+/// The memoizer used by a [L10nBundle] for the formatters, which is either the
+/// default [IntlLangMemoizer] or the thread-safe [concurrent::IntlLangMemoizer].
+pub trait Memoizer: MemoizerKind + Sized {
+    fn bundle(lang_id: LanguageIdentifier) -> FluentBundle<FluentResource, Self>;
+}
+
+impl Memoizer for IntlLangMemoizer {
+    fn bundle(lang_id: LanguageIdentifier) -> FluentBundle<FluentResource, Self> {
+        FluentBundle::new(vec![lang_id])
+    }
+}
+
+impl Memoizer for concurrent::IntlLangMemoizer {
+    fn bundle(lang_id: LanguageIdentifier) -> FluentBundle<FluentResource, Self> {
+        FluentBundle::new_concurrent(vec![lang_id])
+    }
+}
+
+/// A [L10nBundle] that is `Send + Sync` and can be shared between threads.
+pub type ConcurrentL10nBundle = L10nBundle<concurrent::IntlLangMemoizer>;
+
+pub struct L10nBundle<M = IntlLangMemoizer> {
     lang: String,
-    bundle: FluentBundle<FluentResource>,
+    bundle: FluentBundle<FluentResource, M>,
+    embedded: bool,
 }
 
-impl L10nBundle {
+impl<M: Memoizer> L10nBundle<M> {
     pub fn new(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, String> {
         let ftl = String::from_utf8(bytes.to_vec())
             .map_err(|e| format!("Could not read ftl string due to: {e}"))?;
         let lang_id: LanguageIdentifier = lang.as_ref().parse().map_err(|e| format!("{e:?}"))?;
-        let mut bundle = FluentBundle::new(vec![lang_id]);
+        let mut bundle = M::bundle(lang_id);
         let resource = FluentResource::try_new(ftl).map_err(|e| format!("{e:?}"))?;
         bundle
             .add_resource(resource)
@@ -24,6 +48,16 @@ impl L10nBundle {
         Ok(Self {
             bundle,
             lang: lang.as_ref().to_string(),
+            embedded: false,
+        })
+    }
+
+    /// Same as [L10nBundle::new], for the ftl that the code was generated
+    /// from, whose texts can then be used as they were found at build time.
+    pub fn new_embedded(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, String> {
+        Ok(Self {
+            embedded: true,
+            ..Self::new(lang, bytes)?
         })
     }
 
@@ -31,14 +65,78 @@ impl L10nBundle {
         &self.lang
     }
 
+    /// The language of the bundle, when it was loaded with
+    /// [L10nBundle::new_embedded].
+    pub fn embedded_lang(&self) -> Option<&str> {
+        self.embedded.then_some(self.lang.as_str())
+    }
+
     pub fn msg(&self, id: &str, args: Option<FluentArgs>) -> Result<String, String> {
         let pattern = self.try_get_pattern(id, None)?;
         self.format(id, None, pattern, args.as_ref())
+            .map(Cow::into_owned)
     }
 
     pub fn attr(&self, msg: &str, attr: &str, args: Option<FluentArgs>) -> Result<String, String> {
         let pattern = self.try_get_pattern(msg, Some(attr))?;
         self.format(msg, Some(attr), pattern, args.as_ref())
+            .map(Cow::into_owned)
+    }
+
+    /// Same as [L10nBundle::msg], but borrows the text from the bundle when
+    /// the message has no placeables, instead of allocating a String.
+    pub fn msg_cow(&self, id: &str, args: Option<FluentArgs>) -> Result<Cow<'_, str>, String> {
+        let pattern = self.try_get_pattern(id, None)?;
+        match args {
+            None => self.format(id, None, pattern, None),
+            Some(args) => self
+                .format(id, None, pattern, Some(&args))
+                .map(|value| Cow::Owned(value.into_owned())),
+        }
+    }
+
+    /// Same as [L10nBundle::attr], but borrows the text from the bundle when
+    /// the attribute has no placeables, instead of allocating a String.
+    pub fn attr_cow(
+        &self,
+        msg: &str,
+        attr: &str,
+        args: Option<FluentArgs>,
+    ) -> Result<Cow<'_, str>, String> {
+        let pattern = self.try_get_pattern(msg, Some(attr))?;
+        match args {
+            None => self.format(msg, Some(attr), pattern, None),
+            Some(args) => self
+                .format(msg, Some(attr), pattern, Some(&args))
+                .map(|value| Cow::Owned(value.into_owned())),
+        }
+    }
+
+    /// Writes the formatted message into the writer without allocating a
+    /// String. As fmt::Write can't carry the errors, a missing message or
+    /// a formatting error fails with fmt::Error.
+    pub fn write_msg<W: fmt::Write>(
+        &self,
+        w: &mut W,
+        id: &str,
+        args: Option<FluentArgs>,
+    ) -> fmt::Result {
+        let pattern = self.try_get_pattern(id, None).map_err(|_| fmt::Error)?;
+        self.write(w, pattern, args.as_ref())
+    }
+
+    /// Writes the formatted attribute into the writer, see [L10nBundle::write_msg].
+    pub fn write_attr<W: fmt::Write>(
+        &self,
+        w: &mut W,
+        msg: &str,
+        attr: &str,
+        args: Option<FluentArgs>,
+    ) -> fmt::Result {
+        let pattern = self
+            .try_get_pattern(msg, Some(attr))
+            .map_err(|_| fmt::Error)?;
+        self.write(w, pattern, args.as_ref())
     }
 
     pub fn msg_pattern(&self, id: &str) -> Pattern<String> {
@@ -51,7 +149,7 @@ impl L10nBundle {
         to_owned_pattern(pattern)
     }
 
-    fn try_get_pattern(
+    pub(crate) fn try_get_pattern(
         &self,
         msg_id: &str,
         attr_id: Option<&str>,
@@ -78,9 +176,9 @@ impl L10nBundle {
         &'a self,
         msg: &str,
         attr: Option<&str>,
-        pattern: &'a Pattern<&str>,
-        args: Option<&FluentArgs>,
-    ) -> Result<String, String> {
+        pattern: &'a Pattern<&'a str>,
+        args: Option<&'a FluentArgs>,
+    ) -> Result<Cow<'a, str>, String> {
         let mut errors = vec![];
         let value = self.bundle.format_pattern(pattern, args, &mut errors);
         if !errors.is_empty() {
@@ -94,7 +192,22 @@ impl L10nBundle {
                 "Invalid format for {attr_str}message '{msg}'{arg_str}: {errors:?}"
             ))
         } else {
-            Ok(value.to_string())
+            Ok(value)
+        }
+    }
+
+    fn write<W: fmt::Write>(
+        &self,
+        w: &mut W,
+        pattern: &Pattern<&str>,
+        args: Option<&FluentArgs>,
+    ) -> fmt::Result {
+        let mut errors = vec![];
+        self.bundle.write_pattern(w, pattern, args, &mut errors)?;
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(fmt::Error)
         }
     }
 }
@@ -1,29 +1,77 @@
-use std::ops::Range;
+use std::str::FromStr;
 
-use crate::prelude::L10nBundle;
-
-pub struct L10nLanguageVec {
-    langs: Vec<L10nBundle>,
+/// The loaded languages of a generated `L10n` enum, which are indexed by the
+/// discriminant of the enum variant.
+pub struct L10nLanguageVec<L, T> {
+    langs: Vec<Option<(L, T)>>,
 }
 
-impl L10nLanguageVec {
-    pub fn load<S, I>(bytes: &[u8], iter: I) -> Result<Self, String>
+impl<L, T> L10nLanguageVec<L, T>
+where
+    L: Copy + Into<usize>,
+{
+    /// Loads each language, which is stored at the index of its
+    /// discriminant, so that the languages can be given in any order.
+    pub fn new<I, F>(langs: I, mut load: F) -> Self
+    where
+        I: IntoIterator<Item = L>,
+        F: FnMut(L) -> T,
+    {
+        let mut vec = Self { langs: Vec::new() };
+        for lang in langs {
+            vec.insert(lang, load(lang));
+        }
+        vec
+    }
+
+    /// Same as [L10nLanguageVec::new], but fails with the first language
+    /// that could not be loaded.
+    pub fn try_new<I, F>(langs: I, mut load: F) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = L>,
+        F: FnMut(L) -> Result<T, String>,
+    {
+        let mut vec = Self { langs: Vec::new() };
+        for lang in langs {
+            vec.insert(lang, load(lang)?);
+        }
+        Ok(vec)
+    }
+
+    fn insert(&mut self, lang: L, language: T) {
+        let index = lang.into();
+        if self.langs.len() <= index {
+            self.langs.resize_with(index + 1, || None);
+        }
+        self.langs[index] = Some((lang, language));
+    }
+
+    /// The language, which has to be loaded, see [L10nLanguageVec::try_get].
+    pub fn get(&self, lang: L) -> &T {
+        self.try_get(lang)
+            .expect("The language is not one of the loaded languages")
+    }
+
+    /// The language, if it is one of the loaded languages.
+    pub fn try_get(&self, lang: L) -> Option<&T> {
+        let (_, language) = self.langs.get(lang.into())?.as_ref()?;
+        Some(language)
+    }
+
+    /// The language with the given language id, if it is one of the loaded
+    /// languages.
+    pub fn get_by_id(&self, lang: &str) -> Option<&T>
     where
-        S: AsRef<str>,
-        I: Iterator<Item = (S, Range<usize>)>,
+        L: FromStr,
     {
-        Ok(Self {
-            langs: iter
-                .map(|(lang, range)| L10nBundle::new(lang, &bytes[range]))
-                .collect::<Result<Vec<_>, String>>()?,
-        })
+        self.try_get(lang.parse().ok()?)
     }
 
-    /// IMPORTANT, the lang argument should be a L10n enum variant
-    pub fn get(&self, lang: impl AsRef<str>) -> &L10nBundle {
+    /// The loaded languages, in the order of their discriminants.
+    pub fn iter(&self) -> impl Iterator<Item = (L, &T)> {
         self.langs
             .iter()
-            .find(|b| b.lang() == lang.as_ref())
-            .unwrap()
+            .flatten()
+            .map(|(lang, language)| (*lang, language))
     }
 }
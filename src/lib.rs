@@ -1,25 +1,63 @@
 #![doc = include_str!("../README.md")]
 #[cfg(any(doc, feature = "build"))]
 mod build;
+mod codec;
+#[cfg(feature = "hot-reload")]
+mod hot_reload;
 mod l10n_bundle;
 mod l10n_language_vec;
+mod message_signature;
 
 #[cfg(all(test, feature = "build"))]
 mod tests;
 
+pub use codec::Codec;
+
 #[cfg(any(doc, feature = "build"))]
 pub use build::{
-    BuildOptions, FtlOutputOptions, OutputMode, build_from_locales_folder,
-    try_build_from_locales_folder,
+    AdditionalLocales, BuildOptions, Builder, Compressor, DocTranslations, FtlOutputOptions,
+    OutputMode, ValidationPolicy, build_from_locales_folder, try_build_from_locales_folder,
 };
 
+/// Includes the code that was generated into `OUT_DIR` by a build script using
+/// [BuildOptions::with_out_dir].
+///
+/// ```ignore
+/// mod l10n {
+///     fluent_typed::include_l10n!();
+/// }
+/// ```
+///
+/// The file name defaults to "l10n.rs" and can be given when the
+/// `output_file_path` was changed, as in `include_l10n!("my_l10n.rs")`.
+#[macro_export]
+macro_rules! include_l10n {
+    () => {
+        include!(concat!(env!("OUT_DIR"), "/l10n.rs"));
+    };
+    ($file:literal) => {
+        include!(concat!(env!("OUT_DIR"), "/", $file));
+    };
+}
+
 pub mod prelude {
-    pub use crate::l10n_bundle::L10nBundle;
+    pub use crate::codec::Codec;
+    #[cfg(feature = "hot-reload")]
+    pub use crate::hot_reload::{ConcurrentHotReloadBundle, HotReloadBundle};
+    pub use crate::l10n_bundle::{ConcurrentL10nBundle, L10nBundle, Memoizer};
     pub use crate::l10n_language_vec::L10nLanguageVec;
+    pub use crate::message_signature::MessageSignature;
     pub use fluent_bundle::{FluentArgs, FluentValue, types::FluentNumber};
     pub use fluent_syntax::ast::{Pattern, PatternElement};
     #[cfg(feature = "langneg")]
-    pub use icu_locale_core::{langid, LanguageIdentifier};
+    pub use icu_locale_core::{LanguageIdentifier, langid};
+    pub use std::borrow::Cow;
+
+    /// Whether the `hot-reload` feature is enabled, which the code generated
+    /// with hot reload checks at compile time.
+    pub const fn hot_reload_enabled() -> bool {
+        cfg!(feature = "hot-reload")
+    }
 
     #[cfg(feature = "langneg")]
     pub fn negotiate_languages<'a, A>(accept_language: &str, available: &'a [A]) -> A
@@ -44,10 +82,12 @@ pub mod prelude {
                 } else {
                     (entry, 1000)
                 };
-                tag.parse::<LanguageIdentifier>().ok().map(|lid| (lid, quality))
+                tag.parse::<LanguageIdentifier>()
+                    .ok()
+                    .map(|lid| (lid, quality))
             })
             .collect();
-        requested.sort_by(|a, b| b.1.cmp(&a.1));
+        requested.sort_by_key(|r| std::cmp::Reverse(r.1));
 
         // Find the first available language whose language subtag matches a requested one
         for (req, _) in &requested {
@@ -0,0 +1,89 @@
+use fluent_syntax::ast::{Expression, InlineExpression, Pattern, PatternElement};
+
+use crate::l10n_bundle::Memoizer;
+use crate::prelude::L10nBundle;
+
+/// The id and the variables of a message (or message attribute) that the
+/// code was generated for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageSignature {
+    pub message: &'static str,
+    pub attribute: Option<&'static str>,
+    pub variables: &'static [&'static str],
+}
+
+impl MessageSignature {
+    pub const fn msg(message: &'static str, variables: &'static [&'static str]) -> Self {
+        Self {
+            message,
+            attribute: None,
+            variables,
+        }
+    }
+
+    pub const fn attr(
+        message: &'static str,
+        attribute: &'static str,
+        variables: &'static [&'static str],
+    ) -> Self {
+        Self {
+            message,
+            attribute: Some(attribute),
+            variables,
+        }
+    }
+}
+
+impl<M: Memoizer> L10nBundle<M> {
+    /// Checks that the bundle has all the messages and attributes of the
+    /// signatures, using the same variables. Returns the discrepancies.
+    pub fn verify(&self, signatures: &[MessageSignature]) -> Vec<String> {
+        let mut errors = Vec::new();
+        for sig in signatures {
+            let name = match sig.attribute {
+                Some(attr) => format!("attribute '{attr}' of message '{}'", sig.message),
+                None => format!("message '{}'", sig.message),
+            };
+            let pattern = match self.try_get_pattern(sig.message, sig.attribute) {
+                Ok(pattern) => pattern,
+                Err(_) => {
+                    errors.push(format!("Missing {name} in language '{}'", self.lang()));
+                    continue;
+                }
+            };
+            let mut found = variables(pattern);
+            found.sort_unstable();
+            found.dedup();
+            let mut expected = sig.variables.to_vec();
+            expected.sort_unstable();
+            if found != expected {
+                errors.push(format!(
+                    "The {name} in language '{}' uses the variables [{}] instead of [{}]",
+                    self.lang(),
+                    found.join(", "),
+                    expected.join(", ")
+                ));
+            }
+        }
+        errors
+    }
+}
+
+/// The variables of the pattern, found the same way as when generating the code.
+fn variables<'a>(pattern: &Pattern<&'a str>) -> Vec<&'a str> {
+    pattern
+        .elements
+        .iter()
+        .filter_map(|element| match element {
+            PatternElement::Placeable {
+                expression:
+                    Expression::Inline(InlineExpression::VariableReference { id })
+                    | Expression::Select {
+                        selector: InlineExpression::VariableReference { id },
+                        ..
+                    },
+            } => Some(id.name),
+            _ => None,
+        })
+        .collect()
+}
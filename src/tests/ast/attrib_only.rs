@@ -81,6 +81,8 @@ fn typed() {
                 id: "userName".to_string(),
                 typ: VarType::Any
             }],
+            value: "Tooltip for you, { $userName }.".to_string(),
+            text: None,
         }
     );
 }
@@ -78,6 +78,8 @@ fn typed() {
                 id: "duration".to_string(),
                 typ: VarType::Number,
             }],
+            value: "Time elapsed: { $duration }s.".to_string(),
+            text: None,
         }
     );
 }
@@ -89,6 +89,8 @@ fn typed() {
                 id: "var".to_string(),
                 typ: VarType::Any,
             }],
+            value: "{ $var -> [key1] Value 1 *[other] Value 2 }".to_string(),
+            text: None,
         }
     );
 }
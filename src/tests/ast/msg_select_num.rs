@@ -102,6 +102,9 @@ fn typed() {
                 id: "num".to_string(),
                 typ: VarType::Number,
             }],
+            value: "{ $num -> [0] No likes yet. *[other] { $num } people liked your message }"
+                .to_string(),
+            text: None,
         }
     );
 }
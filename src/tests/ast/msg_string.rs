@@ -73,6 +73,8 @@ fn typed() {
                 id: "name".to_string(),
                 typ: VarType::String,
             }],
+            value: "Hi { $name }".to_string(),
+            text: None,
         }
     );
 }
@@ -86,6 +86,8 @@ fn typed() {
             comment: vec!["This is a message comment".to_string()],
             id: Id::new_msg("hello"),
             variables: vec![],
+            value: "Hello World!".to_string(),
+            text: Some("Hello World!".to_string()),
         }
     );
     println!("{:#?}", attr);
@@ -99,6 +101,8 @@ fn typed() {
                 id: "userName".to_string(),
                 typ: VarType::Any
             }],
+            value: "Tooltip for you, { $userName }.".to_string(),
+            text: None,
         }
     );
 }
@@ -69,6 +69,8 @@ fn typed() {
                 id: "first-name".to_string(),
                 typ: VarType::Any
             }],
+            value: "Hi { $first-name }!".to_string(),
+            text: None,
         }
     );
 }
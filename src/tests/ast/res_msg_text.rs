@@ -44,6 +44,8 @@ fn typed() {
             id: Id::new_msg("hello-world"),
             comment: vec![],
             variables: vec![],
+            value: "Hello World!".to_string(),
+            text: Some("Hello World!".to_string()),
         }
     );
 }
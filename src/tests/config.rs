@@ -0,0 +1,132 @@
+use std::fs;
+
+use super::TempDir;
+use crate::{
+    BuildOptions, Codec, Compressor, DocTranslations, FtlOutputOptions, OutputMode,
+    ValidationPolicy,
+};
+
+#[test]
+fn test_config_file() {
+    let options = BuildOptions::from_package_config("src/tests/test_config").unwrap();
+
+    assert_eq!(
+        options.locales_folder,
+        "src/tests/test_config/../test_locales_args"
+    );
+    assert_eq!(
+        options.output_file_path,
+        "src/tests/test_config/../gen/config_gen.rs"
+    );
+    assert_eq!(options.default_language, "de");
+    assert_eq!(
+        options.output_mode,
+        OutputMode::Both {
+            string_prefix: "str_".to_string(),
+            pattern_prefix: "ptn_".to_string()
+        }
+    );
+    assert!(matches!(
+        options.ftl_output,
+        FtlOutputOptions::MultiFile { output_ftl_folder, embed: false } if output_ftl_folder == "src/tests/test_config/../gen/config/"
+    ));
+    assert_eq!(options.args_struct_above, Some(3));
+    assert_eq!(options.doc_translations, DocTranslations::AllLanguages);
+    assert_eq!(options.missing_messages, ValidationPolicy::Error);
+    assert_eq!(options.signature_mismatches, ValidationPolicy::Ignore);
+    assert!(options.format);
+}
+
+#[test]
+fn test_config_in_cargo_metadata() {
+    let temp = TempDir::new("config-metadata");
+    let dir = temp.path();
+    fs::write(
+        dir.join("Cargo.toml"),
+        r#"
+[package]
+name = "app"
+
+[package.metadata.fluent-typed]
+locales-folder = "translations"
+format = false
+"#,
+    )
+    .unwrap();
+
+    let options = BuildOptions::from_package_config(dir.to_str().unwrap()).unwrap();
+    assert_eq!(
+        options.locales_folder,
+        dir.join("translations").to_str().unwrap()
+    );
+    assert!(!options.format);
+    assert_eq!(options.default_language, "en");
+}
+
+#[test]
+fn test_config_unknown_key() {
+    let temp = TempDir::new("config-unknown");
+    let dir = temp.path();
+    let file = dir.join("fluent-typed.toml");
+    fs::write(&file, "locale-folder = \"locales\"\n").unwrap();
+
+    let err = BuildOptions::from_config_file(file.to_str().unwrap())
+        .err()
+        .unwrap();
+    assert!(err.contains("unknown field `locale-folder`"), "{err}");
+}
+
+#[test]
+fn test_config_ftl_codec() {
+    let temp = TempDir::new("config-codec");
+    let dir = temp.path();
+    let file = dir.join("fluent-typed.toml");
+    fs::write(&file, "ftl-codec = \"zstd\"\n").unwrap();
+
+    let options = BuildOptions::from_config_file(file.to_str().unwrap()).unwrap();
+    assert!(matches!(
+        options.ftl_output,
+        FtlOutputOptions::SingleFile {
+            compressor: Some(Compressor::Codec(Codec::Zstd)),
+            ..
+        }
+    ));
+
+    fs::write(&file, "ftl-output = \"inline\"\nftl-codec = \"zstd\"\n").unwrap();
+    let err = BuildOptions::from_config_file(file.to_str().unwrap())
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        "The ftl-codec can only be used with single file ftl output"
+    );
+}
+
+#[test]
+fn test_config_relative_paths() {
+    let temp = TempDir::new("config-paths");
+    let dir = temp.path();
+    let file = dir.join("fluent-typed.toml");
+    fs::write(
+        &file,
+        r#"
+locales-folder = "locales"
+additional-locales = [{ folder = "/common/locales" }]
+ftl-output-path = "gen/translations.ftl"
+"#,
+    )
+    .unwrap();
+
+    let options = BuildOptions::from_config_file(file.to_str().unwrap()).unwrap();
+    assert_eq!(
+        options.locales_folder,
+        dir.join("locales").to_str().unwrap()
+    );
+    assert_eq!(options.additional_locales[0].folder, "/common/locales");
+    assert_eq!(options.output_file_path, "src/l10n.rs");
+    assert!(matches!(
+        options.ftl_output,
+        FtlOutputOptions::SingleFile { output_ftl_file, .. }
+            if output_ftl_file == dir.join("gen/translations.ftl").to_str().unwrap()
+    ));
+}
@@ -13,21 +13,17 @@ static ALL_LANGS: [L10n; 1] = [
     // languages as an array
     L10n::En,
 ];
+static MESSAGE_SIGNATURES: &[MessageSignature] = &[MessageSignature::msg("key", &["var"])];
 
 static EN: LanguageIdentifier = langid!("en");
 
 /// The languages that have translations available.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum L10n {
+    #[default]
     En,
 }
 
-impl Default for L10n {
-    fn default() -> Self {
-        Self::En
-    }
-}
-
 impl FromStr for L10n {
     type Err = String;
 
@@ -68,6 +64,13 @@ impl Display for L10n {
     }
 }
 
+/// The index of the language in [L10nLanguageVec].
+impl From<L10n> for usize {
+    fn from(lang: L10n) -> Self {
+        lang as usize
+    }
+}
+
 impl L10n {
     pub fn iter() -> Iter<'static, L10n> {
         ALL_LANGS.iter()
@@ -88,16 +91,14 @@ impl L10n {
     /// Load a L10nLanguage from the embedded data.
     pub fn load(&self) -> L10nLanguage {
         let bytes = LANG_DATA[self.byte_range()].to_vec();
-        L10nLanguage::new(self, &bytes).unwrap()
+        L10nLanguage::new_embedded(self, &bytes).unwrap()
     }
 
     /// Load all languages (L10nLanguage) from the embedded data.
-    pub fn load_all() -> L10nLanguageVec {
-        L10nLanguageVec::load(
-            LANG_DATA,
-            Self::iter().map(|lang| (lang, lang.byte_range())),
-        )
-        .unwrap()
+    pub fn load_all() -> L10nAll {
+        L10nAll(L10nLanguageVec::new(Self::iter().copied(), |lang| {
+            lang.load()
+        }))
     }
 }
 
@@ -117,9 +118,55 @@ impl L10nLanguage {
         Ok(Self(L10nBundle::new(lang, bytes)?))
     }
 
+    /// Load the L10n resources from the ftl that the code was generated from.
+    #[allow(dead_code)]
+    fn new_embedded(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, String> {
+        Ok(Self(L10nBundle::new_embedded(lang, bytes)?))
+    }
+
+    /// Same as [L10nLanguage::new], but also verifies that the ftl has all
+    /// the messages, attributes and variables that the code was generated
+    /// for, which is useful for ftl files that are loaded at runtime.
+    ///
+    /// Returns the discrepancies that were found.
+    #[allow(dead_code)]
+    pub fn new_verified(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, Vec<String>> {
+        let language = Self::new(lang, bytes).map_err(|e| vec![e])?;
+        let errors = language.0.verify(MESSAGE_SIGNATURES);
+        if errors.is_empty() {
+            Ok(language)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// en: `{ $var -> [key1] Value 1 *[other] Value 2 }`
     pub fn msg_key<'a, F0: Into<FluentValue<'a>>>(&self, var: F0) -> String {
         let mut args = FluentArgs::new();
         args.set("var", var);
         self.0.msg("key", Some(args)).unwrap()
     }
 }
+
+/// All the languages, as loaded by [L10n::load_all].
+pub struct L10nAll(L10nLanguageVec<L10n, L10nLanguage>);
+
+impl L10nAll {
+    pub fn get(&self, lang: L10n) -> &L10nLanguage {
+        self.0.get(lang)
+    }
+
+    /// The language, if it is one of the loaded languages.
+    pub fn try_get(&self, lang: L10n) -> Option<&L10nLanguage> {
+        self.0.try_get(lang)
+    }
+
+    /// The language with the given language id, if it is one of the languages.
+    pub fn get_by_id(&self, lang: &str) -> Option<&L10nLanguage> {
+        self.0.get_by_id(lang)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (L10n, &L10nLanguage)> {
+        self.0.iter()
+    }
+}
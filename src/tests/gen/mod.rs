@@ -9,6 +9,22 @@ mod msg_text_pattern_gen;
 mod msg_with_attrib_gen;
 mod msg_with_var_gen;
 mod res_msg_text_gen;
+pub mod test_args_struct_gen;
+#[cfg(feature = "gzip")]
+pub mod test_codec_gen;
+pub mod test_compressed_gen;
+pub mod test_concurrent_gen;
+pub mod test_const_text_gen;
+pub mod test_cow_strings_gen;
+mod test_doc_translations_gen;
+#[cfg(feature = "hot-reload")]
+pub mod test_hot_reload_embedded_gen;
 mod test_locales_gen;
 mod test_locales_missing_msg_gen;
 mod test_locales_multi_resources_gen;
+pub mod test_message_ids_gen;
+pub mod test_messages_trait_gen;
+pub mod test_multi_file_embedded_gen;
+pub mod test_multi_file_gen;
+pub mod test_strip_ftl_gen;
+pub mod test_write_functions_gen;
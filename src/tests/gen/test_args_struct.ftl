@@ -0,0 +1,48 @@
+
+## ########## Resource: app ###############
+
+language-name = Deutsch
+
+# $name (String) - The name of the user.
+hello = Hallo { $name }!
+
+# $count (Number) - The number of unread messages.
+unread = { $count ->
+    [one] Du hast eine ungelesene Nachricht
+   *[other] Du hast { $count } ungelesene Nachrichten
+}
+
+login = Anmelden
+    .placeholder = Deine E-Mail
+    .tooltip = Als { $user } anmelden
+
+# $first (String) - The first name.
+# $last (String) - The last name.
+# $city (String) - Where the user lives.
+# $age (Number) - The age in years.
+profile-summary = { $first } { $last } aus { $city } ist { $age } Jahre alt
+
+
+## ########## Resource: app ###############
+
+language-name = English
+
+# $name (String) - The name of the user.
+hello = Hello { $name }!
+
+# $count (Number) - The number of unread messages.
+unread = { $count ->
+    [one] You have one unread message
+   *[other] You have { $count } unread messages
+}
+
+login = Log in
+    .placeholder = Your email
+    .tooltip = Log in as { $user }
+
+# $first (String) - The first name.
+# $last (String) - The last name.
+# $city (String) - Where the user lives.
+# $age (Number) - The age in years.
+profile-summary = { $first } { $last } from { $city } is { $age } years old
+
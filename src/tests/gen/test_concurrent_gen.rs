@@ -0,0 +1,249 @@
+// This file is generated. Do not edit it manually.
+use crate::prelude::*;
+use std::{
+    fmt::Display,
+    ops::{Deref, Range},
+    slice::Iter,
+    str::FromStr,
+};
+
+static LANG_DATA: &[u8] = "\n## ########## Resource: app ###############\n\nlanguage-name = Deutsch\n\n# $name (String) - The name of the user.\nhello = Hallo { $name }!\n\n# $count (Number) - The number of unread messages.\nunread = { $count ->\n    [one] Du hast eine ungelesene Nachricht\n   *[other] Du hast { $count } ungelesene Nachrichten\n}\n\nlogin = Anmelden\n    .placeholder = Deine E-Mail\n    .tooltip = Als { $user } anmelden\n\n# $first (String) - The first name.\n# $last (String) - The last name.\n# $city (String) - Where the user lives.\n# $age (Number) - The age in years.\nprofile-summary = { $first } { $last } aus { $city } ist { $age } Jahre alt\n\n\n## ########## Resource: app ###############\n\nlanguage-name = English\n\n# $name (String) - The name of the user.\nhello = Hello { $name }!\n\n# $count (Number) - The number of unread messages.\nunread = { $count ->\n    [one] You have one unread message\n   *[other] You have { $count } unread messages\n}\n\nlogin = Log in\n    .placeholder = Your email\n    .tooltip = Log in as { $user }\n\n# $first (String) - The first name.\n# $last (String) - The last name.\n# $city (String) - Where the user lives.\n# $age (Number) - The age in years.\nprofile-summary = { $first } { $last } from { $city } is { $age } years old\n\n".as_bytes();
+
+static ALL_LANGS: [L10n; 2] = [
+    // languages as an array
+    L10n::De,
+    L10n::En,
+];
+static MESSAGE_SIGNATURES: &[MessageSignature] = &[
+    MessageSignature::msg("language-name", &[]),
+    MessageSignature::msg("hello", &["name"]),
+    MessageSignature::msg("unread", &["count"]),
+    MessageSignature::msg("login", &[]),
+    MessageSignature::attr("login", "placeholder", &[]),
+    MessageSignature::attr("login", "tooltip", &["user"]),
+    MessageSignature::msg("profile-summary", &["first", "last", "city", "age"]),
+];
+
+static DE: LanguageIdentifier = langid!("de");
+static EN: LanguageIdentifier = langid!("en");
+
+/// The languages that have translations available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum L10n {
+    De,
+    #[default]
+    En,
+}
+
+impl FromStr for L10n {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "de" => Ok(Self::De),
+            "en" => Ok(Self::En),
+            _ => Err(format!("Unknown language: {}", s)),
+        }
+    }
+}
+
+impl Deref for L10n {
+    type Target = str;
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::De => "de",
+            Self::En => "en",
+        }
+    }
+}
+
+impl AsRef<LanguageIdentifier> for L10n {
+    fn as_ref(&self) -> &LanguageIdentifier {
+        match self {
+            Self::De => &DE,
+            Self::En => &EN,
+        }
+    }
+}
+
+impl AsRef<str> for L10n {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl Display for L10n {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.deref())
+    }
+}
+
+/// The index of the language in [L10nLanguageVec].
+impl From<L10n> for usize {
+    fn from(lang: L10n) -> Self {
+        lang as usize
+    }
+}
+
+impl L10n {
+    pub fn iter() -> Iter<'static, L10n> {
+        ALL_LANGS.iter()
+    }
+
+    /// The language name as defined in the ftl message "language-name".
+    pub fn language_name(&self) -> &'static str {
+        match self {
+            Self::De => "Deutsch",
+            Self::En => "English",
+        }
+    }
+
+    /// Negotiate the best language to use based on the `Accept-Language` header.
+    ///
+    /// Falls back to the default language if none of the languages in the header are available.
+    pub fn langneg(accept_language: &str) -> L10n {
+        negotiate_languages(accept_language, &ALL_LANGS)
+    }
+
+    fn byte_range(&self) -> Range<usize> {
+        match self {
+            Self::De => 0..623,
+            Self::En => 623..1227,
+        }
+    }
+    /// Load a L10nLanguage from the embedded data.
+    pub fn load(&self) -> L10nLanguage {
+        let bytes = LANG_DATA[self.byte_range()].to_vec();
+        L10nLanguage::new_embedded(self, &bytes).unwrap()
+    }
+
+    /// Load all languages (L10nLanguage) from the embedded data.
+    pub fn load_all() -> L10nAll {
+        L10nAll(L10nLanguageVec::new(Self::iter().copied(), |lang| {
+            lang.load()
+        }))
+    }
+}
+
+/// A thin wrapper around the Fluent messages for one language.
+///
+/// It provides functions for each message that was found in
+/// all the languages at build time.
+pub struct L10nLanguage(ConcurrentL10nBundle);
+
+impl L10nLanguage {
+    /// Load the L10n resources for the given language. The language
+    /// has to be a valid LanguageIdentifier or otherwise
+    /// an error is returned.
+    ///
+    /// The bytes are expected to be the contents of a .ftl file
+    pub fn new(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, String> {
+        Ok(Self(L10nBundle::new(lang, bytes)?))
+    }
+
+    /// Load the L10n resources from the ftl that the code was generated from.
+    #[allow(dead_code)]
+    fn new_embedded(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, String> {
+        Ok(Self(L10nBundle::new_embedded(lang, bytes)?))
+    }
+
+    /// Same as [L10nLanguage::new], but also verifies that the ftl has all
+    /// the messages, attributes and variables that the code was generated
+    /// for, which is useful for ftl files that are loaded at runtime.
+    ///
+    /// Returns the discrepancies that were found.
+    #[allow(dead_code)]
+    pub fn new_verified(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, Vec<String>> {
+        let language = Self::new(lang, bytes).map_err(|e| vec![e])?;
+        let errors = language.0.verify(MESSAGE_SIGNATURES);
+        if errors.is_empty() {
+            Ok(language)
+        } else {
+            Err(errors)
+        }
+    }
+
+    #[allow(unused)]
+    /// en: `English`
+    pub fn msg_language_name(&self) -> String {
+        self.0.msg("language-name", None).unwrap()
+    }
+    /// $name (String) - The name of the user.
+    ///
+    /// en: `Hello { $name }!`
+    pub fn msg_hello<F0: AsRef<str>>(&self, name: F0) -> String {
+        let mut args = FluentArgs::new();
+        args.set("name", name.as_ref());
+        self.0.msg("hello", Some(args)).unwrap()
+    }
+    /// $count (Number) - The number of unread messages.
+    ///
+    /// en: `{ $count -> [one] You have one unread message *[other] You have { $count } unread messages }`
+    pub fn msg_unread<F0: Into<FluentNumber>>(&self, count: F0) -> String {
+        let mut args = FluentArgs::new();
+        args.set("count", count.into());
+        self.0.msg("unread", Some(args)).unwrap()
+    }
+    /// en: `Log in`
+    pub fn msg_login(&self) -> String {
+        self.0.msg("login", None).unwrap()
+    }
+    /// en: `Your email`
+    pub fn msg_login_placeholder(&self) -> String {
+        self.0.attr("login", "placeholder", None).unwrap()
+    }
+    /// en: `Log in as { $user }`
+    pub fn msg_login_tooltip<'a, F0: Into<FluentValue<'a>>>(&self, user: F0) -> String {
+        let mut args = FluentArgs::new();
+        args.set("user", user);
+        self.0.attr("login", "tooltip", Some(args)).unwrap()
+    }
+    /// $first (String) - The first name.
+    /// $last (String) - The last name.
+    /// $city (String) - Where the user lives.
+    /// $age (Number) - The age in years.
+    ///
+    /// en: `{ $first } { $last } from { $city } is { $age } years old`
+    pub fn msg_profile_summary<
+        F0: AsRef<str>,
+        F1: AsRef<str>,
+        F2: AsRef<str>,
+        F3: Into<FluentNumber>,
+    >(
+        &self,
+        first: F0,
+        last: F1,
+        city: F2,
+        age: F3,
+    ) -> String {
+        let mut args = FluentArgs::new();
+        args.set("first", first.as_ref());
+        args.set("last", last.as_ref());
+        args.set("city", city.as_ref());
+        args.set("age", age.into());
+        self.0.msg("profile-summary", Some(args)).unwrap()
+    }
+}
+
+/// All the languages, as loaded by [L10n::load_all].
+pub struct L10nAll(L10nLanguageVec<L10n, L10nLanguage>);
+
+impl L10nAll {
+    pub fn get(&self, lang: L10n) -> &L10nLanguage {
+        self.0.get(lang)
+    }
+
+    /// The language, if it is one of the loaded languages.
+    pub fn try_get(&self, lang: L10n) -> Option<&L10nLanguage> {
+        self.0.try_get(lang)
+    }
+
+    /// The language with the given language id, if it is one of the languages.
+    pub fn get_by_id(&self, lang: &str) -> Option<&L10nLanguage> {
+        self.0.get_by_id(lang)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (L10n, &L10nLanguage)> {
+        self.0.iter()
+    }
+}
@@ -0,0 +1,24 @@
+
+## ########## Resource: app ###############
+
+-brand = Fluent Typed
+
+# Plain text in all languages.
+login = Anmelden
+    .title = Bei Ihrem Konto anmelden
+# Plain text in english only.
+about = Über { -brand }
+hello = Hallo { $name }
+
+
+## ########## Resource: app ###############
+
+-brand = Fluent Typed
+
+# Plain text in all languages.
+login = Log in
+    .title = Log in to your account
+# Plain text in english only.
+about = About
+hello = Hello { $name }
+
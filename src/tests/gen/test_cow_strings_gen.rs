@@ -0,0 +1,235 @@
+// This file is generated. Do not edit it manually.
+use crate::prelude::*;
+use std::{
+    fmt::Display,
+    ops::{Deref, Range},
+    slice::Iter,
+    str::FromStr,
+};
+
+static LANG_DATA: &[u8] = "\n## ########## Resource: app ###############\n\n-brand = Fluent Typed\n\n# Plain text in all languages.\nlogin = Anmelden\n    .title = Bei Ihrem Konto anmelden\n# Plain text in english only.\nabout = Über { -brand }\nhello = Hallo { $name }\n\n\n## ########## Resource: app ###############\n\n-brand = Fluent Typed\n\n# Plain text in all languages.\nlogin = Log in\n    .title = Log in to your account\n# Plain text in english only.\nabout = About\nhello = Hello { $name }\n\n".as_bytes();
+
+static ALL_LANGS: [L10n; 2] = [
+    // languages as an array
+    L10n::De,
+    L10n::En,
+];
+static MESSAGE_SIGNATURES: &[MessageSignature] = &[
+    MessageSignature::msg("login", &[]),
+    MessageSignature::attr("login", "title", &[]),
+    MessageSignature::msg("about", &[]),
+    MessageSignature::msg("hello", &["name"]),
+];
+
+static DE: LanguageIdentifier = langid!("de");
+static EN: LanguageIdentifier = langid!("en");
+
+/// The languages that have translations available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum L10n {
+    De,
+    #[default]
+    En,
+}
+
+impl FromStr for L10n {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "de" => Ok(Self::De),
+            "en" => Ok(Self::En),
+            _ => Err(format!("Unknown language: {}", s)),
+        }
+    }
+}
+
+impl Deref for L10n {
+    type Target = str;
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::De => "de",
+            Self::En => "en",
+        }
+    }
+}
+
+impl AsRef<LanguageIdentifier> for L10n {
+    fn as_ref(&self) -> &LanguageIdentifier {
+        match self {
+            Self::De => &DE,
+            Self::En => &EN,
+        }
+    }
+}
+
+impl AsRef<str> for L10n {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl Display for L10n {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.deref())
+    }
+}
+
+/// The index of the language in [L10nLanguageVec].
+impl From<L10n> for usize {
+    fn from(lang: L10n) -> Self {
+        lang as usize
+    }
+}
+
+impl L10n {
+    pub fn iter() -> Iter<'static, L10n> {
+        ALL_LANGS.iter()
+    }
+
+    /// Negotiate the best language to use based on the `Accept-Language` header.
+    ///
+    /// Falls back to the default language if none of the languages in the header are available.
+    pub fn langneg(accept_language: &str) -> L10n {
+        negotiate_languages(accept_language, &ALL_LANGS)
+    }
+
+    fn byte_range(&self) -> Range<usize> {
+        match self {
+            Self::De => 0..235,
+            Self::En => 235..455,
+        }
+    }
+    /// Load a L10nLanguage from the embedded data.
+    pub fn load(&self) -> L10nLanguage {
+        let bytes = LANG_DATA[self.byte_range()].to_vec();
+        L10nLanguage::new_embedded(self, &bytes).unwrap()
+    }
+
+    /// Load all languages (L10nLanguage) from the embedded data.
+    pub fn load_all() -> L10nAll {
+        L10nAll(L10nLanguageVec::new(Self::iter().copied(), |lang| {
+            lang.load()
+        }))
+    }
+}
+
+/// A thin wrapper around the Fluent messages for one language.
+///
+/// It provides functions for each message that was found in
+/// all the languages at build time.
+pub struct L10nLanguage(L10nBundle);
+
+impl L10nLanguage {
+    /// Load the L10n resources for the given language. The language
+    /// has to be a valid LanguageIdentifier or otherwise
+    /// an error is returned.
+    ///
+    /// The bytes are expected to be the contents of a .ftl file
+    pub fn new(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, String> {
+        Ok(Self(L10nBundle::new(lang, bytes)?))
+    }
+
+    /// Load the L10n resources from the ftl that the code was generated from.
+    #[allow(dead_code)]
+    fn new_embedded(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, String> {
+        Ok(Self(L10nBundle::new_embedded(lang, bytes)?))
+    }
+
+    /// Same as [L10nLanguage::new], but also verifies that the ftl has all
+    /// the messages, attributes and variables that the code was generated
+    /// for, which is useful for ftl files that are loaded at runtime.
+    ///
+    /// Returns the discrepancies that were found.
+    #[allow(dead_code)]
+    pub fn new_verified(lang: impl AsRef<str>, bytes: &[u8]) -> Result<Self, Vec<String>> {
+        let language = Self::new(lang, bytes).map_err(|e| vec![e])?;
+        let errors = language.0.verify(MESSAGE_SIGNATURES);
+        if errors.is_empty() {
+            Ok(language)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Plain text in all languages.
+    ///
+    /// en: `Log in`
+    pub fn msg_login(&self) -> Cow<'_, str> {
+        self.0.msg_cow("login", None).unwrap()
+    }
+    /// en: `Log in to your account`
+    pub fn msg_login_title(&self) -> Cow<'_, str> {
+        self.0.attr_cow("login", "title", None).unwrap()
+    }
+    /// Plain text in english only.
+    ///
+    /// en: `About`
+    pub fn msg_about(&self) -> Cow<'_, str> {
+        self.0.msg_cow("about", None).unwrap()
+    }
+    /// en: `Hello { $name }`
+    pub fn msg_hello<'a, F0: Into<FluentValue<'a>>>(&self, name: F0) -> Cow<'_, str> {
+        let mut args = FluentArgs::new();
+        args.set("name", name);
+        self.0.msg_cow("hello", Some(args)).unwrap()
+    }
+}
+
+/// All the languages, as loaded by [L10n::load_all].
+pub struct L10nAll(L10nLanguageVec<L10n, L10nLanguage>);
+
+impl L10nAll {
+    pub fn get(&self, lang: L10n) -> &L10nLanguage {
+        self.0.get(lang)
+    }
+
+    /// The language, if it is one of the loaded languages.
+    pub fn try_get(&self, lang: L10n) -> Option<&L10nLanguage> {
+        self.0.try_get(lang)
+    }
+
+    /// The language with the given language id, if it is one of the languages.
+    pub fn get_by_id(&self, lang: &str) -> Option<&L10nLanguage> {
+        self.0.get_by_id(lang)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (L10n, &L10nLanguage)> {
+        self.0.iter()
+    }
+}
+
+/// The functions for all the messages that were found in all the languages
+/// at build time.
+///
+/// It is implemented by [L10nLanguage] and lets code that formats messages
+/// be used with mocks or alternative implementations.
+pub trait L10nMessages {
+    /// Plain text in all languages.
+    ///
+    /// en: `Log in`
+    fn msg_login(&self) -> Cow<'_, str>;
+    /// en: `Log in to your account`
+    fn msg_login_title(&self) -> Cow<'_, str>;
+    /// Plain text in english only.
+    ///
+    /// en: `About`
+    fn msg_about(&self) -> Cow<'_, str>;
+    /// en: `Hello { $name }`
+    fn msg_hello<'a, F0: Into<FluentValue<'a>>>(&self, name: F0) -> Cow<'_, str>;
+}
+
+impl L10nMessages for L10nLanguage {
+    fn msg_login(&self) -> Cow<'_, str> {
+        L10nLanguage::msg_login(self)
+    }
+    fn msg_login_title(&self) -> Cow<'_, str> {
+        L10nLanguage::msg_login_title(self)
+    }
+    fn msg_about(&self) -> Cow<'_, str> {
+        L10nLanguage::msg_about(self)
+    }
+    fn msg_hello<'a, F0: Into<FluentValue<'a>>>(&self, name: F0) -> Cow<'_, str> {
+        L10nLanguage::msg_hello(self, name)
+    }
+}
@@ -0,0 +1,9 @@
+-brand = Fluent Typed
+hello = Hallo { $name }, willkommen bei { -brand }!
+about = Über { -brand }
+    .title = Über { -brand }
+-brand = Fluent Typed
+hello = Hello { $name }, welcome to { -brand }!
+about = { tagline }
+    .title = About { -brand }
+tagline = Typed translations
@@ -0,0 +1,64 @@
+use std::{
+    fs, thread,
+    time::{Duration, Instant},
+};
+
+use super::TempDir;
+use crate::prelude::{ConcurrentHotReloadBundle, HotReloadBundle, MessageSignature};
+
+static SIGNATURES: &[MessageSignature] = &[MessageSignature::msg("hello", &["name"])];
+
+fn hello<M: crate::prelude::Memoizer>(bundle: &HotReloadBundle<M>) -> String {
+    let mut args = fluent_bundle::FluentArgs::new();
+    args.set("name", "Anna");
+    bundle.msg("hello", Some(args)).unwrap()
+}
+
+#[test]
+fn test_hot_reload() {
+    let locales = TempDir::new("hot-reload");
+    let file = locales.path().join("en/app.ftl");
+    fs::create_dir_all(file.parent().unwrap()).unwrap();
+    fs::write(&file, "hello = Hello { $name }!\n").unwrap();
+
+    let base = locales.path().to_str().unwrap();
+    let bundle: HotReloadBundle =
+        HotReloadBundle::new("en", b"", base, &["."], SIGNATURES).unwrap();
+    assert_eq!(hello(&bundle), "Hello \u{2068}Anna\u{2069}!");
+
+    fs::write(&file, "hello = Hi { $name }!\n").unwrap();
+    bundle.reload().unwrap();
+    assert_eq!(hello(&bundle), "Hi \u{2068}Anna\u{2069}!");
+
+    // a translation that doesn't match the generated code isn't loaded
+    fs::write(&file, "hello = Hi there!\n").unwrap();
+    assert_eq!(
+        bundle.reload().unwrap_err(),
+        "The message 'hello' in language 'en' uses the variables [] instead of [name]"
+    );
+    assert_eq!(hello(&bundle), "Hi \u{2068}Anna\u{2069}!");
+}
+
+#[test]
+fn test_hot_reload_watches_changes() {
+    let locales = TempDir::new("hot-reload-watch");
+    let file = locales.path().join("en/app.ftl");
+    fs::create_dir_all(file.parent().unwrap()).unwrap();
+    fs::write(&file, "hello = Hello { $name }!\n").unwrap();
+
+    let folders = [locales.path().to_str().unwrap()];
+    let bundle = ConcurrentHotReloadBundle::new("en", b"", "", &folders, SIGNATURES).unwrap();
+
+    // the size differs, so the change is noticed even with coarse modification times
+    fs::write(&file, "hello = Hi { $name }!\n").unwrap();
+    let start = Instant::now();
+    while hello(&bundle) != "Hi \u{2068}Anna\u{2069}!" {
+        assert!(start.elapsed() < Duration::from_secs(10), "no reload");
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    // the bundle can be shared between threads
+    thread::scope(|s| {
+        s.spawn(|| assert_eq!(hello(&bundle), "Hi \u{2068}Anna\u{2069}!"));
+    });
+}
@@ -1,14 +1,55 @@
 mod ast;
 mod complex;
+#[cfg(feature = "config")]
+mod config;
 mod r#gen;
+#[cfg(feature = "hot-reload")]
+mod hot_reload;
 
 use std::fs;
+#[cfg(any(feature = "config", feature = "hot-reload"))]
+use std::{
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
-use crate::{BuildOptions, FtlOutputOptions, OutputMode, build::Builder};
+use crate::{
+    BuildOptions, DocTranslations, FtlOutputOptions, OutputMode, ValidationPolicy,
+    build::{Builder, r#gen::GeneratedFtl},
+};
 
 use fluent_bundle::{FluentBundle, FluentResource};
 use unic_langid::langid;
 
+/// A unique folder in the temp dir for a test, which is removed when dropped.
+#[cfg(any(feature = "config", feature = "hot-reload"))]
+pub(crate) struct TempDir(PathBuf);
+
+#[cfg(any(feature = "config", feature = "hot-reload"))]
+impl TempDir {
+    pub(crate) fn new(name: &str) -> Self {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let dir = std::env::temp_dir().join(format!(
+            "fluent-typed-{name}-{}-{}",
+            std::process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        Self(dir)
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+#[cfg(any(feature = "config", feature = "hot-reload"))]
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
 fn bundle(ftl: &str) -> FluentBundle<FluentResource> {
     let res = FluentResource::try_new(ftl.to_string()).expect("Failed to parse an FTL string.");
 
@@ -64,7 +105,7 @@ fn assert_gen_with_output_mode(
 #[test]
 fn test_locales_folder() {
     let ftl_opts = FtlOutputOptions::SingleFile {
-        output_ftl_file: format!("src/tests/gen/test_locales.ftl"),
+        output_ftl_file: "src/tests/gen/test_locales.ftl".to_string(),
         compressor: None,
     };
     let options = BuildOptions::default()
@@ -78,7 +119,7 @@ fn test_locales_folder() {
 #[test]
 fn test_locales_multi_resources() {
     let ftl_opts = FtlOutputOptions::SingleFile {
-        output_ftl_file: format!("src/tests/gen/test_locales_multi_resources.ftl"),
+        output_ftl_file: "src/tests/gen/test_locales_multi_resources.ftl".to_string(),
         compressor: None,
     };
     let options = BuildOptions::default()
@@ -93,7 +134,7 @@ fn test_locales_multi_resources() {
 #[test]
 fn test_locales_missing_msg() {
     let ftl_opts = FtlOutputOptions::SingleFile {
-        output_ftl_file: format!("src/tests/gen/test_locales_missing_msg.ftl"),
+        output_ftl_file: "src/tests/gen/test_locales_missing_msg.ftl".to_string(),
         compressor: None,
     };
     let options = BuildOptions::default()
@@ -179,6 +220,764 @@ fn test_locales_deep_folders() {
     assert!(generated.contains("L10n::En"));
 }
 
+#[test]
+fn test_message_ids() {
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_args")
+        .with_ftl_output(FtlOutputOptions::single_file(
+            "src/tests/gen/test_message_ids.ftl",
+        ))
+        .with_output_file_path("src/tests/gen/test_message_ids_gen.rs")
+        .with_message_ids();
+    Builder::load(options).unwrap().generate().unwrap();
+
+    use r#gen::test_message_ids_gen::{L10n, MessageId};
+
+    let id = MessageId::Hello {
+        name: "Anna".to_string(),
+    };
+    assert_eq!(id.message(), "hello");
+    assert_eq!(L10n::En.load().get(&id), "Hello \u{2068}Anna\u{2069}!");
+    assert_eq!(L10n::De.load().get(&id), "Hallo \u{2068}Anna\u{2069}!");
+
+    let id = MessageId::LoginTooltip {
+        user: "anna".into(),
+    };
+    assert_eq!(id.attribute(), Some("tooltip"));
+    assert_eq!(
+        L10n::De.load().get(&id),
+        "Als \u{2068}anna\u{2069} anmelden"
+    );
+}
+
+#[test]
+fn test_doc_translations() {
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_args")
+        .with_ftl_output(FtlOutputOptions::single_file(
+            "src/tests/gen/test_doc_translations.ftl",
+        ))
+        .with_output_file_path("src/tests/gen/test_doc_translations_gen.rs")
+        .with_doc_translations(DocTranslations::AllLanguages);
+    Builder::load(options).unwrap().generate().unwrap();
+
+    let generated = fs::read_to_string("src/tests/gen/test_doc_translations_gen.rs").unwrap();
+    assert!(generated.contains(
+        r#"    /// $name (String) - The name of the user.
+    ///
+    /// | Language | Translation |
+    /// |---|---|
+    /// | de | `Hallo { $name }!` |
+    /// | en | `Hello { $name }!` |
+    pub fn msg_hello"#
+    ));
+}
+
+#[test]
+fn test_out_dir_paths() {
+    let mut options = BuildOptions::default().with_out_dir();
+    options.move_into("target/out");
+    assert_eq!(options.output_file_path, "target/out/l10n.rs");
+    let FtlOutputOptions::SingleFile {
+        output_ftl_file, ..
+    } = &options.ftl_output
+    else {
+        panic!("expected single file output");
+    };
+    assert_eq!(output_ftl_file, "target/out/translations.ftl");
+
+    let mut options = BuildOptions::default()
+        .with_ftl_output(FtlOutputOptions::multi_file_embedded("gen/ftl/"))
+        .with_out_dir();
+    options.move_into("target/out");
+    assert_eq!(options.output_file_path, "target/out/l10n.rs");
+    let FtlOutputOptions::MultiFile {
+        output_ftl_folder,
+        embed: true,
+    } = &options.ftl_output
+    else {
+        panic!("expected embedded multi file output");
+    };
+    assert_eq!(output_ftl_folder, "target/out");
+
+    let generated = GeneratedFtl::MultiFile {
+        output_ftl_folder: output_ftl_folder.clone(),
+        files: vec![("en".to_string(), "en.ftl".to_string())],
+        embed: true,
+    };
+    let include = generated
+        .include_replacement(&options.output_file_path, true)
+        .unwrap();
+    assert!(include.contains(
+        r#"static EN_FTL: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/en.ftl"));"#
+    ));
+}
+
+#[test]
+fn test_new_verified() {
+    use r#gen::test_message_ids_gen::L10nLanguage;
+
+    let ftl = fs::read("src/tests/test_locales_args/en/app.ftl").unwrap();
+    assert!(L10nLanguage::new_verified("en", &ftl).is_ok());
+
+    let ftl = r#"
+language-name = English
+hello = Hello { $user }!
+unread = { $count } unread
+login = Log in
+    .placeholder = Your email
+profile-summary = { $first } { $last } from { $city } is { $age } years old
+"#;
+    let errors = L10nLanguage::new_verified("en", ftl.as_bytes())
+        .err()
+        .unwrap();
+    assert_eq!(
+        errors,
+        [
+            "The message 'hello' in language 'en' uses the variables [user] instead of [name]",
+            "Missing attribute 'tooltip' of message 'login' in language 'en'",
+        ]
+    );
+}
+
+#[test]
+fn test_multi_file() {
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_args")
+        .with_ftl_output(FtlOutputOptions::multi_file("src/tests/gen/multi"))
+        .with_output_file_path("src/tests/gen/test_multi_file_gen.rs");
+    Builder::load(options).unwrap().generate().unwrap();
+
+    use r#gen::test_multi_file_gen::L10n;
+    use std::{future::Future, pin::pin, task};
+
+    assert_eq!(L10n::De.ftl_file_name(), "de.ftl");
+
+    let de = L10n::De.load_from_folder("src/tests/gen/multi").unwrap();
+    assert_eq!(de.msg_hello("Anna"), "Hallo \u{2068}Anna\u{2069}!");
+
+    let err = L10n::En
+        .load_from_folder("src/tests/gen/none")
+        .err()
+        .unwrap();
+    assert!(err.starts_with("Could not read ftl file"));
+
+    let fetch = |name: &str| {
+        let url = format!("src/tests/gen/multi/{name}");
+        async move { fs::read(url) }
+    };
+    let mut future = pin!(L10n::En.load_with(fetch));
+    let mut cx = task::Context::from_waker(task::Waker::noop());
+    let task::Poll::Ready(en) = future.as_mut().poll(&mut cx) else {
+        panic!("expected the future to be ready");
+    };
+    assert_eq!(en.unwrap().msg_hello("Anna"), "Hello \u{2068}Anna\u{2069}!");
+}
+
+#[test]
+fn test_multi_file_embedded() {
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_args")
+        .with_ftl_output(FtlOutputOptions::multi_file_embedded(
+            "src/tests/gen/embedded",
+        ))
+        .with_output_file_path("src/tests/gen/test_multi_file_embedded_gen.rs");
+    Builder::load(options).unwrap().generate().unwrap();
+
+    use r#gen::test_multi_file_embedded_gen::{L10n, L10nLanguage};
+
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<L10nLanguage>();
+
+    let de = std::thread::spawn(|| L10n::De.get()).join().unwrap();
+    assert!(std::ptr::eq(de, L10n::De.get()));
+    assert_eq!(de.msg_hello("Anna"), "Hallo \u{2068}Anna\u{2069}!");
+    assert_eq!(
+        L10n::En.load().msg_hello("Anna"),
+        "Hello \u{2068}Anna\u{2069}!"
+    );
+}
+
+#[test]
+fn test_compressed_languages() {
+    fn reverse(bytes: &[u8]) -> Vec<u8> {
+        bytes.iter().rev().copied().collect()
+    }
+    let ftl_opts =
+        FtlOutputOptions::single_compressed_file("src/tests/gen/test_compressed.ftl.rev", |b| {
+            Ok(reverse(&b))
+        });
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_args")
+        .with_ftl_output(ftl_opts)
+        .with_output_file_path("src/tests/gen/test_compressed_gen.rs");
+    Builder::load(options).unwrap().generate().unwrap();
+
+    use r#gen::test_compressed_gen::L10n;
+    use std::cell::RefCell;
+
+    let decompressed = RefCell::new(Vec::new());
+    let decompressor = |bytes: &[u8]| {
+        let ftl = String::from_utf8(reverse(bytes)).unwrap();
+        let name = ftl.lines().find(|l| l.starts_with("language-name"));
+        decompressed.borrow_mut().push(name.unwrap().to_string());
+        Ok(ftl.into_bytes())
+    };
+
+    let de = L10n::De.load(decompressor).unwrap();
+    assert_eq!(de.msg_hello("Anna"), "Hallo \u{2068}Anna\u{2069}!");
+    assert_eq!(*decompressed.borrow(), ["language-name = Deutsch"]);
+
+    L10n::load_all(decompressor).unwrap();
+    assert_eq!(decompressed.borrow().len(), 3);
+}
+
+#[test]
+#[cfg(feature = "gzip")]
+fn test_codec() {
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_args")
+        .with_ftl_output(FtlOutputOptions::single_file_with_codec(
+            "src/tests/gen/test_codec.ftl.gz",
+            crate::Codec::Gzip,
+        ))
+        .with_output_file_path("src/tests/gen/test_codec_gen.rs");
+    Builder::load(options).unwrap().generate().unwrap();
+
+    use r#gen::test_codec_gen::L10n;
+
+    let code = fs::read_to_string("src/tests/gen/test_codec_gen.rs").unwrap();
+    assert!(code.contains(
+        r#"const _: () = assert!(
+    Codec::Gzip.is_enabled(),
+    "The gzip codec requires the 'gzip' feature of fluent-typed"
+);"#
+    ));
+
+    let data = fs::read("src/tests/gen/test_codec.ftl.gz").unwrap();
+    assert_eq!(data[0], crate::Codec::Gzip.header());
+
+    let de = L10n::De.load().unwrap();
+    assert_eq!(de.msg_hello("Anna"), "Hallo \u{2068}Anna\u{2069}!");
+    assert!(L10n::load_all().is_ok());
+}
+
+#[test]
+fn test_codecs() {
+    use crate::Codec;
+
+    let ftl = b"hello = Hello { $name }!";
+    for codec in [Codec::Gzip, Codec::Zstd, Codec::Brotli] {
+        let enabled = codec.is_enabled();
+        match codec.compress(ftl) {
+            Ok(compressed) => {
+                assert!(enabled);
+                assert_eq!(Codec::decompress(&compressed).unwrap(), ftl);
+            }
+            Err(e) => {
+                assert!(!enabled);
+                assert!(e.ends_with("feature of fluent-typed"), "{e}");
+            }
+        }
+    }
+    assert_eq!(
+        Codec::decompress(&[9, 1, 2]).err().unwrap(),
+        "Unknown compression header: 9"
+    );
+}
+
+#[test]
+fn test_strip_ftl() {
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_strip")
+        .with_ftl_output(FtlOutputOptions::single_file(
+            "src/tests/gen/test_strip_ftl.ftl",
+        ))
+        .with_output_file_path("src/tests/gen/test_strip_ftl_gen.rs")
+        .with_missing_messages(ValidationPolicy::Ignore)
+        .with_strip_ftl();
+    let builder = Builder::load(options).unwrap();
+    builder.generate().unwrap();
+
+    let ftl = fs::read_to_string("src/tests/gen/test_strip_ftl.ftl").unwrap();
+    assert_eq!(
+        ftl,
+        r#"-brand = Fluent Typed
+hello = Hallo { $name }, willkommen bei { -brand }!
+about = Über { -brand }
+    .title = Über { -brand }
+-brand = Fluent Typed
+hello = Hello { $name }, welcome to { -brand }!
+about = { tagline }
+    .title = About { -brand }
+tagline = Typed translations
+"#
+    );
+    assert!(
+        builder
+            .report()
+            .contains("en: stripped ftl 149 bytes, saved 181 of 330 bytes (54%)")
+    );
+    let strip_report = builder.strip_report();
+    assert_eq!(strip_report.len(), 2);
+    assert_eq!(
+        strip_report[1],
+        "en: stripped ftl 149 bytes, saved 181 of 330 bytes (54%)"
+    );
+
+    use r#gen::test_strip_ftl_gen::L10n;
+    assert_eq!(L10n::En.load().msg_about(), "Typed translations");
+}
+
+#[test]
+fn test_const_text() {
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_const_text")
+        .with_ftl_output(FtlOutputOptions::single_file(
+            "src/tests/gen/test_const_text.ftl",
+        ))
+        .with_output_file_path("src/tests/gen/test_const_text_gen.rs")
+        .with_const_text();
+    let builder = Builder::load(options).unwrap();
+    builder.generate().unwrap();
+
+    let code = fs::read_to_string("src/tests/gen/test_const_text_gen.rs").unwrap();
+    assert!(code.contains(r#"Some("de") => "Anmelden","#));
+    assert!(!code.contains(r#"Some("de") => "Über"#));
+
+    use r#gen::test_const_text_gen::{L10n, L10nLanguage};
+    use std::borrow::Cow;
+    let en = L10n::En.load();
+    let de = L10n::De.load();
+    assert!(matches!(de.msg_login(), Cow::Borrowed("Anmelden")));
+    assert!(matches!(
+        en.msg_login_title(),
+        Cow::Borrowed("Log in to your account")
+    ));
+    assert!(matches!(en.msg_about(), Cow::Borrowed("About")));
+    assert!(matches!(de.msg_about(), Cow::Owned(_)));
+    assert_eq!(de.msg_about(), "Über Fluent Typed");
+    assert_eq!(de.msg_hello("Ana"), "Hallo \u{2068}Ana\u{2069}");
+
+    // ftl loaded at runtime may differ from the texts found at build time
+    let runtime = L10nLanguage::new("de", "login = Einloggen".as_bytes()).unwrap();
+    assert!(matches!(runtime.msg_login(), Cow::Owned(text) if text == "Einloggen"));
+}
+
+#[test]
+fn test_cow_strings() {
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_const_text")
+        .with_ftl_output(FtlOutputOptions::Inline)
+        .with_output_file_path("src/tests/gen/test_cow_strings_gen.rs")
+        .with_messages_trait()
+        .with_cow_strings();
+    let builder = Builder::load(options).unwrap();
+    builder.generate().unwrap();
+
+    use r#gen::test_cow_strings_gen::{L10n, L10nMessages};
+    use std::borrow::Cow;
+    let de = L10n::De.load();
+    assert!(matches!(de.msg_login(), Cow::Borrowed("Anmelden")));
+    assert!(matches!(
+        de.msg_login_title(),
+        Cow::Borrowed("Bei Ihrem Konto anmelden")
+    ));
+    assert!(matches!(de.msg_about(), Cow::Owned(_)));
+    assert_eq!(de.msg_about(), "Über Fluent Typed");
+    assert_eq!(de.msg_hello("Ana"), "Hallo \u{2068}Ana\u{2069}");
+
+    fn login<M: L10nMessages>(messages: &M) -> Cow<'_, str> {
+        messages.msg_login()
+    }
+    assert!(matches!(login(&de), Cow::Borrowed("Anmelden")));
+}
+
+#[test]
+fn test_localize() {
+    use r#gen::test_message_ids_gen::{L10n, Localize, MessageId};
+
+    #[derive(Debug)]
+    struct DomainError(MessageId);
+
+    fn assert_send<T: Send + 'static>(_: &T) {}
+
+    let err = DomainError(MessageId::Unread { count: 3.into() });
+    assert_send(&err);
+
+    let localized: &dyn Localize = &err.0;
+    assert_eq!(
+        localized.localize(&L10n::En.load()),
+        "You have \u{2068}3\u{2069} unread messages"
+    );
+    assert_eq!(
+        localized.localize(&L10n::De.load()),
+        "Du hast \u{2068}3\u{2069} ungelesene Nachrichten"
+    );
+}
+
+#[test]
+fn test_messages_trait() {
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_args")
+        .with_ftl_output(FtlOutputOptions::single_file(
+            "src/tests/gen/test_messages_trait.ftl",
+        ))
+        .with_output_file_path("src/tests/gen/test_messages_trait_gen.rs")
+        .with_messages_trait();
+    Builder::load(options).unwrap().generate().unwrap();
+
+    let generated = fs::read_to_string("src/tests/gen/test_messages_trait_gen.rs").unwrap();
+    assert!(generated.contains(
+        r#"    /// $name (String) - The name of the user.
+    ///
+    /// en: `Hello { $name }!`
+    fn msg_hello<F0: AsRef<str>>(&self, name: F0) -> String;"#
+    ));
+
+    use fluent_bundle::{FluentValue, types::FluentNumber};
+    use r#gen::test_messages_trait_gen::{L10n, L10nMessages};
+
+    struct IdEcho;
+
+    impl L10nMessages for IdEcho {
+        fn msg_language_name(&self) -> String {
+            "language-name".to_string()
+        }
+        fn msg_hello<F0: AsRef<str>>(&self, _name: F0) -> String {
+            "hello".to_string()
+        }
+        fn msg_unread<F0: Into<FluentNumber>>(&self, _count: F0) -> String {
+            "unread".to_string()
+        }
+        fn msg_login(&self) -> String {
+            "login".to_string()
+        }
+        fn msg_login_placeholder(&self) -> String {
+            "login.placeholder".to_string()
+        }
+        fn msg_login_tooltip<'a, F0: Into<FluentValue<'a>>>(&self, _user: F0) -> String {
+            "login.tooltip".to_string()
+        }
+        fn msg_profile_summary<
+            F0: AsRef<str>,
+            F1: AsRef<str>,
+            F2: AsRef<str>,
+            F3: Into<FluentNumber>,
+        >(
+            &self,
+            _first: F0,
+            _last: F1,
+            _city: F2,
+            _age: F3,
+        ) -> String {
+            "profile-summary".to_string()
+        }
+    }
+
+    fn greet(messages: &impl L10nMessages) -> String {
+        messages.msg_hello("Anna")
+    }
+
+    assert_eq!(greet(&L10n::En.load()), "Hello \u{2068}Anna\u{2069}!");
+    assert_eq!(greet(&IdEcho), "hello");
+}
+
+#[test]
+fn test_args_struct() {
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_args")
+        .with_ftl_output(FtlOutputOptions::single_file(
+            "src/tests/gen/test_args_struct.ftl",
+        ))
+        .with_output_file_path("src/tests/gen/test_args_struct_gen.rs")
+        .with_args_struct_above(3)
+        .with_messages_trait();
+    Builder::load(options).unwrap().generate().unwrap();
+
+    use r#gen::test_args_struct_gen::{L10n, ProfileSummaryArgs};
+
+    let args = ProfileSummaryArgs {
+        first: "Anna",
+        last: "Berg",
+        city: "Oslo",
+        age: 33.into(),
+    };
+    assert_eq!(
+        L10n::En.load().msg_profile_summary(args),
+        "\u{2068}Anna\u{2069} \u{2068}Berg\u{2069} from \u{2068}Oslo\u{2069} is \u{2068}33\u{2069} years old"
+    );
+}
+
+#[test]
+fn test_write_functions() {
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_args")
+        .with_ftl_output(FtlOutputOptions::Inline)
+        .with_output_file_path("src/tests/gen/test_write_functions_gen.rs")
+        .with_args_struct_above(3)
+        .with_messages_trait()
+        .with_write_prefix("write_");
+    Builder::load(options).unwrap().generate().unwrap();
+
+    use r#gen::test_write_functions_gen::{L10n, L10nMessages, ProfileSummaryArgs};
+
+    let en = L10n::En.load();
+    let mut out = String::new();
+    en.write_login(&mut out).unwrap();
+    out.push_str(" | ");
+    en.write_login_tooltip(&mut out, "anna").unwrap();
+    out.push_str(" | ");
+    en.write_unread(&mut out, 2).unwrap();
+    out.push_str(" | ");
+    let args = ProfileSummaryArgs {
+        first: "Anna",
+        last: "Berg",
+        city: "Oslo",
+        age: 33.into(),
+    };
+    L10nMessages::write_profile_summary(&en, &mut out, args).unwrap();
+    assert_eq!(
+        out,
+        "Log in | Log in as \u{2068}anna\u{2069} | You have \u{2068}2\u{2069} unread messages | \u{2068}Anna\u{2069} \u{2068}Berg\u{2069} from \u{2068}Oslo\u{2069} is \u{2068}33\u{2069} years old"
+    );
+    assert_eq!(out.split(" | ").next(), Some(en.msg_login().as_str()));
+}
+
+#[test]
+fn test_concurrent() {
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_args")
+        .with_ftl_output(FtlOutputOptions::Inline)
+        .with_output_file_path("src/tests/gen/test_concurrent_gen.rs")
+        .with_concurrent();
+    Builder::load(options).unwrap().generate().unwrap();
+
+    use crate::prelude::L10nLanguageVec;
+    use r#gen::test_concurrent_gen::{L10n, L10nAll, L10nLanguage};
+    use std::sync::LazyLock;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<L10nLanguage>();
+    assert_send_sync::<L10nLanguageVec<L10n, L10nLanguage>>();
+    assert_send_sync::<L10nAll>();
+
+    static LANGUAGES: LazyLock<L10nAll> = LazyLock::new(L10n::load_all);
+    let login = std::thread::spawn(|| LANGUAGES.get(L10n::De).msg_login())
+        .join()
+        .unwrap();
+    assert_eq!(login, "Anmelden");
+}
+
+#[test]
+fn test_load_all() {
+    use r#gen::test_args_struct_gen::L10n;
+
+    let languages = L10n::load_all();
+    assert_eq!(languages.get(L10n::De).msg_login(), "Anmelden");
+    assert_eq!(languages.get(L10n::En).msg_login(), "Log in");
+    assert_eq!(
+        languages.get_by_id("de").map(|lang| lang.msg_login()),
+        Some("Anmelden".to_string())
+    );
+    assert!(languages.get_by_id("fr").is_none());
+    assert!(languages.try_get(L10n::En).is_some());
+    assert_eq!(
+        languages
+            .iter()
+            .map(|(lang, language)| (lang, language.msg_language_name()))
+            .collect::<Vec<_>>(),
+        vec![
+            (L10n::De, "Deutsch".to_string()),
+            (L10n::En, "English".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_language_vec() {
+    use crate::prelude::L10nLanguageVec;
+    use r#gen::test_args_struct_gen::L10n;
+
+    let languages = L10nLanguageVec::new([L10n::En], |lang| lang.to_string());
+    assert_eq!(languages.get(L10n::En), "en");
+    assert!(languages.try_get(L10n::De).is_none());
+    assert!(languages.get_by_id("de").is_none());
+
+    let languages = L10nLanguageVec::new([L10n::En, L10n::De], |lang| lang.to_string());
+    assert_eq!(languages.get(L10n::De), "de");
+    assert_eq!(languages.get_by_id("en").unwrap(), "en");
+    assert_eq!(
+        languages.iter().map(|(lang, _)| lang).collect::<Vec<_>>(),
+        [L10n::De, L10n::En]
+    );
+}
+
+#[test]
+fn test_report_and_keys() {
+    let options = BuildOptions::default().with_locales_folder("src/tests/test_locales_args");
+    let builder = Builder::load(options).unwrap();
+
+    assert!(builder.warnings().is_empty());
+    assert_eq!(
+        builder.message_keys(),
+        [
+            "hello $name (String)",
+            "language-name",
+            "login",
+            "login.placeholder",
+            "login.tooltip $user (Any)",
+            "profile-summary $first (String) $last (String) $city (String) $age (Number)",
+            "unread $count (Number)",
+        ]
+    );
+    insta::assert_snapshot!(builder.report(), @r"
+    Languages: 2 (de, en)
+    Messages in all languages: 7
+    de: 7 messages
+    en: 7 messages
+    Missing messages: 0
+    Signature mismatches: 0
+    ");
+}
+
+#[test]
+fn test_validation_policies() {
+    let load = |policy| {
+        let options = BuildOptions::default()
+            .with_locales_folder("src/tests/test_locales_missing_msg")
+            .with_default_language("en-gb")
+            .with_missing_messages(policy);
+        Builder::load(options).unwrap()
+    };
+
+    assert_eq!(load(ValidationPolicy::Warn).validate().unwrap().len(), 4);
+    assert!(
+        load(ValidationPolicy::Ignore)
+            .validate()
+            .unwrap()
+            .is_empty()
+    );
+    let err = load(ValidationPolicy::Error).validate().unwrap_err();
+    assert!(err.contains("Missing message 'name' for languages: de"));
+}
+
+#[test]
+fn test_additional_locales() {
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_args")
+        .with_additional_locales("src/tests/test_locales_common", Some("common"))
+        .with_ftl_output(FtlOutputOptions::inline());
+    let builder = Builder::load(options).unwrap();
+
+    assert!(builder.warnings().is_empty());
+    let keys = builder.message_keys();
+    assert!(keys.contains(&"brand-name".to_string()));
+    assert!(keys.contains(&"hello $name (String)".to_string()));
+    assert!(
+        builder
+            .generate_code()
+            .unwrap()
+            .contains("pub fn msg_cancel(&self)")
+    );
+
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_common")
+        .with_additional_locales("src/tests/test_locales_common", Some("common"));
+    let err = Builder::load(options).err().unwrap();
+    assert_eq!(
+        err,
+        "The term '-brand' is defined in both resource 'brand' and 'common/brand' for language 'de'"
+    );
+
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_common")
+        .with_additional_locales("src/tests/test_locales_collisions", None);
+    let err = Builder::load(options).err().unwrap();
+    assert_eq!(
+        err,
+        "The term '-brand' is defined in both resource 'brand' and 'collisions' for language 'de'"
+    );
+
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_collisions")
+        .with_additional_locales("src/tests/test_locales_collisions", Some("other"));
+    let err = Builder::load(options).err().unwrap();
+    assert_eq!(
+        err,
+        "The message 'logo' is defined in both resource 'collisions' and 'other/collisions' for language 'de'"
+    );
+}
+
+#[test]
+fn test_rerun_instructions() {
+    let options =
+        BuildOptions::default().with_locales_folder("src/tests/test_locales_deep_folders");
+    let instructions = Builder::load(options)
+        .unwrap()
+        .rerun_instructions()
+        .unwrap()
+        .into_iter()
+        .filter(|i| i.contains("/en"))
+        .collect::<Vec<_>>();
+    insta::assert_snapshot!(instructions.join("\n"), @r"
+    cargo::rerun-if-changed=src/tests/test_locales_deep_folders/en
+    cargo::rerun-if-changed=src/tests/test_locales_deep_folders/en/level1
+    cargo::rerun-if-changed=src/tests/test_locales_deep_folders/en/level1/level2
+    cargo::rerun-if-changed=src/tests/test_locales_deep_folders/en/level1/level2/level3
+    cargo::rerun-if-changed=src/tests/test_locales_deep_folders/en/level1/level1-messages.ftl
+    cargo::rerun-if-changed=src/tests/test_locales_deep_folders/en/level1/level2/level2-messages.ftl
+    cargo::rerun-if-changed=src/tests/test_locales_deep_folders/en/level1/level2/level3/deepest.ftl
+    cargo::rerun-if-changed=src/tests/test_locales_deep_folders/en/root.ftl
+    ");
+}
+
+#[test]
+fn test_hot_reload_generation() {
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_args")
+        .with_ftl_output(FtlOutputOptions::inline())
+        .with_hot_reload();
+    let code = Builder::load(options).unwrap().generate_code().unwrap();
+
+    assert!(
+        code.contains(r#"static LOCALES_FOLDERS: &[&str] = &["src/tests/test_locales_args"];"#)
+    );
+    assert!(code.contains(r#"env!("CARGO_MANIFEST_DIR"), LOCALES_FOLDERS"#));
+    assert!(code.contains("hot_reload_enabled(),"));
+    assert!(code.contains(r#"    MessageSignature::attr("login", "tooltip", &["user"]),"#));
+    assert!(code.contains("pub struct L10nLanguage(HotReloadBundle);"));
+
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_args")
+        .with_ftl_output(FtlOutputOptions::inline())
+        .with_hot_reload()
+        .with_concurrent();
+    let code = Builder::load(options).unwrap().generate_code().unwrap();
+    assert!(code.contains("pub struct L10nLanguage(ConcurrentHotReloadBundle);"));
+}
+
+#[cfg(feature = "hot-reload")]
+#[test]
+fn test_hot_reload_embedded() {
+    let options = BuildOptions::default()
+        .with_locales_folder("src/tests/test_locales_args")
+        .with_ftl_output(FtlOutputOptions::multi_file_embedded(
+            "src/tests/gen/hot_reload_embedded",
+        ))
+        .with_output_file_path("src/tests/gen/test_hot_reload_embedded_gen.rs")
+        .with_hot_reload();
+    Builder::load(options).unwrap().generate().unwrap();
+
+    use r#gen::test_hot_reload_embedded_gen::{L10n, L10nLanguage};
+
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<L10nLanguage>();
+
+    let de = std::thread::spawn(|| L10n::De.get()).join().unwrap();
+    assert_eq!(de.msg_hello("Anna"), "Hallo \u{2068}Anna\u{2069}!");
+}
+
 // #[test]
 // fn test_locales_ld() {
 //     let locales = build::from_locales_folder("../../../LeaveDates/frontend/app/locales").unwrap();
@@ -0,0 +1,20 @@
+language-name = Deutsch
+
+# $name (String) - The name of the user.
+hello = Hallo { $name }!
+
+# $count (Number) - The number of unread messages.
+unread = { $count ->
+    [one] Du hast eine ungelesene Nachricht
+   *[other] Du hast { $count } ungelesene Nachrichten
+}
+
+login = Anmelden
+    .placeholder = Deine E-Mail
+    .tooltip = Als { $user } anmelden
+
+# $first (String) - The first name.
+# $last (String) - The last name.
+# $city (String) - Where the user lives.
+# $age (Number) - The age in years.
+profile-summary = { $first } { $last } aus { $city } ist { $age } Jahre alt
@@ -0,0 +1,4 @@
+logo =
+    .alt = Acme Logo
+
+-brand = Acme Corp
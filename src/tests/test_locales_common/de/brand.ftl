@@ -0,0 +1,4 @@
+-brand = Acme
+
+brand-name = { -brand }
+cancel = Abbrechen
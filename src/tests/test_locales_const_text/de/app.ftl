@@ -0,0 +1,8 @@
+-brand = Fluent Typed
+
+# Plain text in all languages.
+login = Anmelden
+    .title = Bei Ihrem Konto anmelden
+# Plain text in english only.
+about = Über { -brand }
+hello = Hallo { $name }
@@ -0,0 +1,8 @@
+-brand = Fluent Typed
+
+# Plain text in all languages.
+login = Log in
+    .title = Log in to your account
+# Plain text in english only.
+about = About
+hello = Hello { $name }
@@ -0,0 +1,10 @@
+### Nachrichten der App
+
+-brand = Fluent Typed
+-unused = Nicht benutzt
+
+# $name (String) - The name of the user.
+hello = Hallo { $name }, willkommen bei { -brand }!
+
+about = Über { -brand }
+    .title = Über { -brand }
@@ -0,0 +1,15 @@
+### Messages of the app
+
+-brand = Fluent Typed
+
+# $name (String) - The name of the user.
+hello = Hello { $name }, welcome to { -brand }!
+
+about = { tagline }
+    .title = About { -brand }
+
+## Only used by the about message
+
+tagline = Typed translations
+
+unused = Not used in the app
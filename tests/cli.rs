@@ -0,0 +1,164 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+};
+
+use insta::assert_snapshot;
+
+/// Runs the fluent-typed binary in the root of the package.
+fn fluent_typed<const N: usize>(args: [&str; N]) -> Output {
+    let out = Command::new("cargo")
+        .args([
+            "run",
+            "-q",
+            "--features",
+            "cli",
+            "--bin",
+            "fluent-typed",
+            "--",
+        ])
+        .args(args)
+        .output()
+        .unwrap();
+    println!("{}", String::from_utf8_lossy(&out.stderr));
+    out
+}
+
+fn stdout(out: &Output) -> String {
+    String::from_utf8(out.stdout.clone()).unwrap()
+}
+
+fn stderr(out: &Output) -> String {
+    String::from_utf8(out.stderr.clone()).unwrap()
+}
+
+/// A folder in the target folder for the files of a test, which is removed
+/// when dropped.
+struct TestDir(PathBuf);
+
+impl TestDir {
+    fn new(name: &str) -> Self {
+        let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("cli-{name}"));
+        if dir.exists() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+        fs::create_dir_all(&dir).unwrap();
+        Self(dir)
+    }
+
+    fn path(&self, name: &str) -> String {
+        self.0.join(name).to_string_lossy().to_string()
+    }
+}
+
+impl Drop for TestDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+#[test]
+fn check() {
+    let out = fluent_typed(["--locales", "src/tests/test_locales_args", "check"]);
+    assert!(out.status.success());
+    assert_eq!(stdout(&out), "");
+
+    // missing messages are warnings, which fail the check
+    let out = fluent_typed([
+        "--locales",
+        "src/tests/test_locales_missing_msg",
+        "--default-language",
+        "en-gb",
+        "check",
+    ]);
+    assert!(!out.status.success());
+    assert!(stderr(&out).contains("warning: Missing message 'name' for languages: de"));
+}
+
+#[test]
+fn check_with_config() {
+    let dir = TestDir::new("check-with-config");
+    let config = dir.path("fluent-typed.toml");
+    let locales = fs::canonicalize("src/tests/test_locales_missing_msg").unwrap();
+    fs::write(
+        &config,
+        format!(
+            "locales-folder = {:?}\ndefault-language = \"en-gb\"\nmissing-messages = \"error\"\n",
+            locales.to_string_lossy()
+        ),
+    )
+    .unwrap();
+
+    // with the error policy, the missing messages are reported as an error
+    let out = fluent_typed(["--config", &config, "check"]);
+    assert!(!out.status.success());
+    assert!(!stderr(&out).contains("warning:"));
+    assert!(stderr(&out).contains("Missing message 'name' for languages: de"));
+
+    // the flags override the configuration
+    let out = fluent_typed([
+        "--config",
+        &config,
+        "--locales",
+        "src/tests/test_locales_args",
+        "--default-language",
+        "en",
+        "check",
+    ]);
+    assert!(out.status.success(), "{}", stderr(&out));
+}
+
+#[test]
+fn list_keys() {
+    let out = fluent_typed(["--locales", "src/tests/test_locales_args", "list-keys"]);
+    assert!(out.status.success());
+    assert_snapshot!(stdout(&out), @r"
+    hello $name (String)
+    language-name
+    login
+    login.placeholder
+    login.tooltip $user (Any)
+    profile-summary $first (String) $last (String) $city (String) $age (Number)
+    unread $count (Number)
+    ");
+}
+
+#[test]
+fn generate_embedded_ftl_folder() {
+    let dir = TestDir::new("generate-embedded");
+    let output = dir.path("l10n.rs");
+    let ftl_folder = dir.path("ftl");
+    let out = fluent_typed([
+        "--locales",
+        "src/tests/test_locales_args",
+        "generate",
+        "--output",
+        &output,
+        "--ftl-folder",
+        &ftl_folder,
+        "--embed",
+    ]);
+    assert!(out.status.success(), "{}", stderr(&out));
+
+    assert!(Path::new(&ftl_folder).join("de.ftl").exists());
+    assert!(Path::new(&ftl_folder).join("en.ftl").exists());
+    let code = fs::read_to_string(&output).unwrap();
+    assert!(code.contains(r#"static DE_FTL: &[u8] = include_bytes!("ftl/de.ftl");"#));
+    assert!(code.contains("pub fn get(&self) -> &'static L10nLanguage"));
+
+    // the folder is embedded only with --embed
+    let out = fluent_typed([
+        "--locales",
+        "src/tests/test_locales_args",
+        "generate",
+        "--output",
+        &output,
+        "--ftl-folder",
+        &ftl_folder,
+    ]);
+    assert!(out.status.success(), "{}", stderr(&out));
+    let code = fs::read_to_string(&output).unwrap();
+    assert!(!code.contains("include_bytes!"));
+    assert!(code.contains("pub fn load_from_folder("));
+}
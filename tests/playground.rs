@@ -20,6 +20,10 @@ fn build_example1() {
     if multi_l10n.exists() {
         fs::remove_file(&multi_l10n).unwrap();
     }
+    let embedded_l10n = root.join("src/embedded_l10n.rs");
+    if embedded_l10n.exists() {
+        fs::remove_file(&embedded_l10n).unwrap();
+    }
 
     let target = root.join("target");
     if target.exists() {
@@ -35,12 +39,16 @@ fn build_example1() {
     assert!(single_l10n.exists());
     assert!(single_gzip_l10n.exists());
     assert!(multi_l10n.exists());
+    assert!(embedded_l10n.exists());
 
     let listing = ls_ascii(&r#gen, 0).unwrap();
     assert_snapshot!(&listing, @r###"
     gen/
       translations.ftl (452 bytes)
-      translations.ftl.gzip (209 bytes)
+      translations.ftl.gzip (317 bytes)
+      embedded/
+        en.ftl (209 bytes)
+        fr.ftl (243 bytes)
       multi/
         en.ftl (209 bytes)
         fr.ftl (243 bytes)